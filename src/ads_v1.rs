@@ -1,3 +1,4 @@
 pub mod sp;
 pub mod sp_global;
+pub mod sb;
 pub mod types;
\ No newline at end of file
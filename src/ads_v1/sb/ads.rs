@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::AdsOperation;
+use crate::util::{wrap_include, wrap_include_optional};
+
+use anyhow::Result;
+use bon::{bon, Builder};
+
+use super::types::{
+    ads::{SBAd, SBAdMultiStatusSuccess, SBAdUpdate},
+    enums::SBStateFilter,
+};
+use crate::ads_v1::types::ErrorsIndex;
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListAds {
+    ads_client: Arc<AdsClient>,
+    filter: ListAdsFilter,
+}
+#[bon]
+impl ListAds {
+    #[builder]
+    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListAdsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+}
+
+impl AdsOperation for ListAds {
+    type Response = ListAdsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/query/ads".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
+    }
+}
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct DelAds {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    ad_ids: Vec<String>,
+}
+
+impl DelAds {
+    pub async fn fetch(self) -> Result<OperationAdsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for DelAds {
+    type Response = OperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/delete/ads".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "adIds": self.ad_ids
+        })))
+    }
+}
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct UpdateAds {
+    ads_client: Arc<AdsClient>,
+    ads: Vec<SBAdUpdate>,
+}
+
+impl UpdateAds {
+    pub async fn fetch(self) -> Result<OperationAdsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateAds {
+    type Response = OperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/update/ads".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "ads": self.ads
+        })))
+    }
+}
+
+// region ListAdsFilter
+
+#[skip_serializing_none]
+#[derive(Serialize, Builder, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAdsFilter {
+    #[serde(serialize_with = "wrap_include")]
+    #[builder(default = vec!["SPONSORED_BRANDS".to_string()])]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_product_filter: Vec<String>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_group_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub campaign_id_filter: Option<Vec<String>>,
+
+    #[builder(default = 1000)]
+    max_results: i32,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    state_filter: Option<Vec<SBStateFilter>>,
+}
+
+// endregion
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAdsResponse {
+    pub ads: Option<Vec<SBAd>>,
+    pub next_token: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationAdsResponse {
+    pub error: Option<Vec<ErrorsIndex>>,
+    pub success: Option<Vec<SBAdMultiStatusSuccess>>,
+}
@@ -0,0 +1,92 @@
+use crate::client::AdsClient;
+use crate::operation::AdsOperation;
+use crate::util::{wrap_include, wrap_include_optional};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::types::enums::SBTargetType;
+use super::types::targets::{ListSBTargetsResponse, OperationTargetResponse, SBTargetUpdate};
+use anyhow::Result;
+use bon::{bon, Builder};
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListTargets {
+    ads_client: Arc<AdsClient>,
+    filter: ListTargetsFilter,
+}
+
+#[bon]
+impl ListTargets {
+    #[builder]
+    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListSBTargetsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+}
+
+impl AdsOperation for ListTargets {
+    type Response = ListSBTargetsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/query/targets".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Builder, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTargetsFilter {
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    ad_group_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include")]
+    #[builder(default = vec!["SPONSORED_BRANDS".to_string()])]
+    ad_product_filter: Vec<String>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    campaign_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    target_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    target_type_filter: Option<Vec<SBTargetType>>,
+}
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct UpdateTarget {
+    ads_client: Arc<AdsClient>,
+    targets: Vec<SBTargetUpdate>,
+}
+
+impl UpdateTarget {
+    pub async fn fetch(self) -> Result<OperationTargetResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateTarget {
+    type Response = OperationTargetResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/update/targets".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "targets": self.targets
+        })))
+    }
+}
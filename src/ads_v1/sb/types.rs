@@ -0,0 +1,5 @@
+pub mod ad_groups;
+pub mod ads;
+pub mod campaigns;
+pub mod enums;
+pub mod targets;
@@ -0,0 +1,65 @@
+use super::ads::{SBCreateTag, SBStatus, SBTag};
+use super::enums::{SBMarketplace, SBState, SBUpdateState};
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::str::FromStr;
+
+// region SBAdGroup
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdGroup {
+    pub ad_group_id: String,
+    pub ad_product: String,
+    pub campaign_id: String,
+
+    #[serde(rename = "creationDateTime")]
+    pub creation_datetime: DateTime<Utc>,
+
+    #[serde(rename = "lastUpdatedDateTime")]
+    pub last_updated_datetime: Option<DateTime<Utc>>,
+    pub marketplace_scope: String,
+    pub marketplaces: Vec<SBMarketplace>,
+    pub name: String,
+    pub state: SBState,
+    pub status: Option<SBStatus>,
+    pub tags: Option<Vec<SBTag>>,
+}
+
+// endregion
+
+// region SBAdGroupUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdGroupUpdate {
+    #[builder(start_fn)]
+    pub ad_group_id: String,
+
+    #[builder(field)]
+    pub state: Option<SBUpdateState>,
+
+    pub name: Option<String>,
+    pub tags: Option<Vec<SBCreateTag>>,
+}
+
+impl<S: s_b_ad_group_update_builder::State> SBAdGroupUpdateBuilder<S> {
+    pub fn state(mut self, state: &str) -> Self {
+        let state = SBUpdateState::from_str(state).unwrap();
+        self.state = Some(state);
+        self
+    }
+}
+
+// endregion
+
+// region SBAdGroupMultiStatusSuccess
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdGroupMultiStatusSuccess {
+    pub ad_group: SBAdGroup,
+    pub index: usize,
+}
+// endregion
@@ -0,0 +1,91 @@
+use super::enums::{SBDeliveryReason, SBDeliveryStatus, SBMarketplace, SBState, SBUpdateState};
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::str::FromStr;
+
+// region SBAd
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAd {
+    pub ad_group_id: String,
+    pub ad_id: String,
+    pub ad_product: String,
+    pub ad_type: Option<String>,
+    pub campaign_id: String,
+
+    #[serde(rename = "creationDateTime")]
+    pub creation_datetime: DateTime<Utc>,
+
+    #[serde(rename = "lastUpdatedDateTime")]
+    pub last_updated_datetime: Option<DateTime<Utc>>,
+    pub marketplace_scope: String,
+    pub marketplaces: Vec<SBMarketplace>,
+    pub name: Option<String>,
+    pub state: SBState,
+    pub status: Option<SBStatus>,
+    pub tags: Option<Vec<SBTag>>,
+}
+
+// endregion
+
+// region SBAdUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdUpdate {
+    #[builder(start_fn)]
+    pub ad_id: String,
+
+    #[builder(field)]
+    pub state: Option<SBUpdateState>,
+
+    pub name: Option<String>,
+    pub tags: Option<Vec<SBCreateTag>>,
+}
+
+impl<S: s_b_ad_update_builder::State> SBAdUpdateBuilder<S> {
+    pub fn state(mut self, state: &str) -> Self {
+        let state = SBUpdateState::from_str(state).unwrap();
+        self.state = Some(state);
+        self
+    }
+}
+
+// endregion
+
+// region SBAdMultiStatusSuccess
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdMultiStatusSuccess {
+    pub ad: SBAd,
+    pub index: usize,
+}
+// endregion
+
+// region SBStatus
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBStatus {
+    pub delivery_reasons: Option<Vec<SBDeliveryReason>>,
+    pub delivery_status: SBDeliveryStatus,
+}
+// endregion
+
+// region SBTag
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBTag {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBCreateTag {
+    pub key: String,
+    pub value: String,
+}
+// endregion
@@ -0,0 +1,165 @@
+use super::ads::{SBCreateTag, SBStatus, SBTag};
+use super::enums::{
+    SBBudgetType, SBCostType, SBCurrencyCode, SBMarketplace, SBRecurrenceTimePeriod, SBState,
+    SBUpdateState,
+};
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::str::FromStr;
+
+// region SBCampaign
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBCampaign {
+    pub ad_product: String,
+    pub budgets: Option<Vec<SBBudget>>,
+    pub campaign_id: String,
+    pub cost_type: Option<SBCostType>,
+
+    #[serde(rename = "creationDateTime")]
+    pub creation_datetime: DateTime<Utc>,
+
+    #[serde(rename = "endDateTime")]
+    pub end_datetime: Option<DateTime<Utc>>,
+
+    #[serde(rename = "lastUpdatedDateTime")]
+    pub last_updated_datetime: Option<DateTime<Utc>>,
+    pub marketplace_scope: String,
+    pub marketplaces: Vec<SBMarketplace>,
+    pub name: String,
+    pub portfolio_id: Option<String>,
+
+    #[serde(rename = "startDateTime")]
+    pub start_datetime: Option<DateTime<Utc>>,
+    pub state: SBState,
+    pub status: Option<SBStatus>,
+    pub tags: Option<Vec<SBTag>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBBudget {
+    pub budget_type: SBBudgetType,
+    pub budget_value: SBBudgetValue,
+    pub recurrence_time_period: SBRecurrenceTimePeriod,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBBudgetValue {
+    pub monetary_budget_value: Option<SBMonetaryBudgetValue>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBMonetaryBudgetValue {
+    pub monetary_budget: SBMonetaryBudget,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBMonetaryBudget {
+    pub currency_code: Option<SBCurrencyCode>,
+    pub value: f64,
+}
+
+// endregion
+
+// region SBCampaignUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBCampaignUpdate {
+    #[builder(start_fn)]
+    pub campaign_id: String,
+
+    #[builder(field)]
+    pub budgets: Option<Vec<SBUpdateBudget>>,
+
+    #[builder(field)]
+    pub state: Option<SBUpdateState>,
+
+    #[serde(rename = "endDateTime")]
+    pub end_datetime: Option<DateTime<Utc>>,
+    pub name: Option<String>,
+    pub portfolio_id: Option<String>,
+    pub tags: Option<Vec<SBCreateTag>>,
+}
+
+impl<S: s_b_campaign_update_builder::State> SBCampaignUpdateBuilder<S> {
+    pub fn state(mut self, state: &str) -> Self {
+        let state = SBUpdateState::from_str(state).unwrap();
+        self.state = Some(state);
+        self
+    }
+
+    pub fn daily_budget(mut self, budget: f64) -> Self {
+        self.budgets = Some(vec![SBUpdateBudget::new(
+            SBRecurrenceTimePeriod::Daily,
+            budget,
+        )]);
+        self
+    }
+
+    pub fn lifetime_budget(mut self, budget: f64) -> Self {
+        self.budgets = Some(vec![SBUpdateBudget::new(
+            SBRecurrenceTimePeriod::Lifetime,
+            budget,
+        )]);
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBUpdateBudget {
+    pub budget_type: SBBudgetType,
+    pub budget_value: SBUpdateBudgetValue,
+    pub recurrence_time_period: SBRecurrenceTimePeriod,
+}
+
+impl SBUpdateBudget {
+    fn new(recurrence_time_period: SBRecurrenceTimePeriod, value: f64) -> Self {
+        Self {
+            budget_type: SBBudgetType::Monetary,
+            budget_value: SBUpdateBudgetValue {
+                monetary_budget_value: SBUpdateMonetaryBudgetValue {
+                    monetary_budget: SBUpdateMonetaryBudget { value },
+                },
+            },
+            recurrence_time_period,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBUpdateBudgetValue {
+    pub monetary_budget_value: SBUpdateMonetaryBudgetValue,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBUpdateMonetaryBudgetValue {
+    pub monetary_budget: SBUpdateMonetaryBudget,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBUpdateMonetaryBudget {
+    pub value: f64,
+}
+
+// endregion
+
+// region SBCampaignMultiStatusSuccess
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBCampaignMultiStatusSuccess {
+    pub campaign: SBCampaign,
+    pub index: usize,
+}
+// endregion
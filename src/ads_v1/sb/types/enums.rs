@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumString};
+
+// 站点/币种/投放状态与 SP 完全一致, 直接复用
+pub use crate::ads_v1::sp::types::enums::{
+    SPCurrencyCode as SBCurrencyCode, SPDeliveryReason as SBDeliveryReason,
+    SPDeliveryStatus as SBDeliveryStatus, SPMarketplace as SBMarketplace,
+};
+
+#[derive(Debug, Deserialize, AsRefStr, PartialEq, Eq)]
+pub enum SBState {
+    ARCHIVED,
+    ENABLED,
+    PAUSED,
+}
+
+#[derive(Debug, Serialize, Deserialize, EnumString, AsRefStr, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBUpdateState {
+    Enabled,
+    Paused,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBStateFilter {
+    Enabled,
+    Paused,
+    Archived,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBBudgetType {
+    Monetary,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBRecurrenceTimePeriod {
+    Daily,
+    Lifetime,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBCostType {
+    Cpc,
+    Vcpm,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBTargetType {
+    Keyword,
+    Product,
+    ProductCategory,
+    Theme,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBKeywordMatchType {
+    Broad,
+    Exact,
+    Phrase,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBProductMatchType {
+    ProductExact,
+    ProductSimilar,
+}
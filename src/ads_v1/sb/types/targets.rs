@@ -0,0 +1,154 @@
+use super::ads::{SBCreateTag, SBStatus, SBTag};
+use super::enums::{
+    SBCurrencyCode, SBKeywordMatchType, SBMarketplace, SBProductMatchType, SBState, SBTargetType,
+    SBUpdateState,
+};
+use crate::ads_v1::types::ErrorsIndex;
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::str::FromStr;
+
+// region ListTargets
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListSBTargetsResponse {
+    pub next_token: Option<String>,
+    pub targets: Option<Vec<SBTarget>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBTarget {
+    pub ad_group_id: Option<String>,
+    pub ad_product: String,
+    pub bid: Option<SBTargetBid>,
+    pub campaign_id: Option<String>,
+
+    #[serde(rename = "creationDateTime")]
+    pub creation_datetime: DateTime<Utc>,
+
+    #[serde(rename = "lastUpdatedDateTime")]
+    pub last_updated_datetime: Option<DateTime<Utc>>,
+    pub marketplace_scope: String,
+    pub marketplaces: Vec<SBMarketplace>,
+    pub negative: bool,
+    pub state: SBState,
+    pub status: Option<SBStatus>,
+    pub tags: Option<Vec<SBTag>>,
+    pub target_details: Option<SBTargetDetail>,
+    pub target_id: String,
+    pub target_type: SBTargetType,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBTargetBid {
+    pub bid: Option<f64>,
+    pub currency_code: SBCurrencyCode,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SBTargetDetail {
+    KeywordTarget(SBKeywordTarget),
+    ProductCategoryTarget(SBProductCategoryTarget),
+    ProductTarget(SBProductTarget),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBKeywordTarget {
+    pub keyword: String,
+    pub match_type: SBKeywordMatchType,
+    pub native_language_keyword: Option<String>,
+    pub native_language_locale: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBProductCategoryTarget {
+    pub product_category_refinement: SBProductCategoryRefinement,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBProductCategoryRefinement {
+    pub product_brand_id: Option<String>,
+    pub product_brand_id_resolved: Option<String>,
+    pub product_category_id: Option<String>,
+    pub product_category_id_resolved: Option<String>,
+    pub product_price_greater_than: Option<f64>,
+    pub product_price_less_than: Option<f64>,
+    pub product_prime_shipping_eligible: Option<bool>,
+    pub product_rating_greater_than: Option<f64>,
+    pub product_rating_less_than: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBProductTarget {
+    pub match_type: SBProductMatchType,
+    pub product: SBProductValue,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBProductValue {
+    pub product_id: String,
+}
+
+// endregion
+
+// region UpdateTargets
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBTargetUpdate {
+    #[builder(start_fn)]
+    pub target_id: String,
+    #[builder(field)]
+    pub bid: Option<SBUpdateTargetBid>,
+    #[builder(field)]
+    pub state: Option<SBUpdateState>,
+    pub tags: Option<Vec<SBCreateTag>>,
+}
+
+impl<S: s_b_target_update_builder::State> SBTargetUpdateBuilder<S> {
+    pub fn bid(mut self, bid: f64) -> Self {
+        self.bid = Some(SBUpdateTargetBid { bid: Some(bid) });
+        self
+    }
+
+    pub fn state(mut self, state: &str) -> Self {
+        let state = SBUpdateState::from_str(state).unwrap();
+        self.state = Some(state);
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBUpdateTargetBid {
+    bid: Option<f64>,
+}
+
+// endregion
+
+// region OperationTargetResponse
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationTargetResponse {
+    pub error: Option<Vec<ErrorsIndex>>,
+    pub success: Option<Vec<SBTargetMultiStatusSuccess>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBTargetMultiStatusSuccess {
+    pub index: usize,
+    pub target: SBTarget,
+}
+// endregion
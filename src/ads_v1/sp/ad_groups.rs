@@ -0,0 +1,182 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::AdsOperation;
+use crate::util::{wrap_include, wrap_include_optional};
+
+use anyhow::Result;
+use bon::{bon, Builder};
+
+use super::types::{
+    ad_groups::{SPAdGroup, SPAdGroupCreate, SPAdGroupMultiStatusSuccess, SPAdGroupUpdate},
+    enums::SPAdGroupStateFilter,
+};
+use crate::ads_v1::types::ErrorsIndex;
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+
+// region ListAdGroups
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListAdGroups {
+    ads_client: Arc<AdsClient>,
+    filter: ListAdGroupsFilter,
+}
+
+#[bon]
+impl ListAdGroups {
+    #[builder]
+    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListAdGroupsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+}
+
+impl AdsOperation for ListAdGroups {
+    type Response = ListAdGroupsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/query/adGroups".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Builder, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAdGroupsFilter {
+    #[serde(serialize_with = "wrap_include")]
+    #[builder(default = vec!["SPONSORED_PRODUCTS".to_string()])]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_product_filter: Vec<String>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_group_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub campaign_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub name_filter: Option<Vec<String>>,
+
+    #[builder(default = 1000)]
+    max_results: i32,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    state_filter: Option<Vec<SPAdGroupStateFilter>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAdGroupsResponse {
+    pub ad_groups: Option<Vec<SPAdGroup>>,
+    pub next_token: Option<String>,
+}
+
+// endregion
+
+// region CreateAdGroups
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct CreateAdGroups {
+    ads_client: Arc<AdsClient>,
+    ad_groups: Vec<SPAdGroupCreate>,
+}
+
+impl CreateAdGroups {
+    pub async fn fetch(self) -> Result<OperationAdGroupsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateAdGroups {
+    type Response = OperationAdGroupsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/create/adGroups".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "adGroups": self.ad_groups
+        })))
+    }
+}
+
+// endregion
+
+// region UpdateAdGroups
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct UpdateAdGroups {
+    ads_client: Arc<AdsClient>,
+    ad_groups: Vec<SPAdGroupUpdate>,
+}
+
+impl UpdateAdGroups {
+    pub async fn fetch(self) -> Result<OperationAdGroupsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateAdGroups {
+    type Response = OperationAdGroupsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/update/adGroups".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "adGroups": self.ad_groups
+        })))
+    }
+}
+
+// endregion
+
+// region DelAdGroups
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct DelAdGroups {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    ad_group_ids: Vec<String>,
+}
+
+impl DelAdGroups {
+    pub async fn fetch(self) -> Result<OperationAdGroupsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for DelAdGroups {
+    type Response = OperationAdGroupsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/delete/adGroups".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "adGroupIds": self.ad_group_ids
+        })))
+    }
+}
+
+// endregion
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationAdGroupsResponse {
+    pub error: Option<Vec<ErrorsIndex>>,
+    pub success: Option<Vec<SPAdGroupMultiStatusSuccess>>,
+}
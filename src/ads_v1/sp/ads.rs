@@ -1,17 +1,22 @@
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{json, Value};
 
+use crate::batch::BatchOperation;
 use crate::client::AdsClient;
+use crate::multi_status::MultiStatus;
+use crate::operation::AdsOperation;
+use crate::pagination::{collect_all, paginate, Collected};
 use crate::util::{wrap_include, wrap_include_optional};
 
 use anyhow::Result;
-use bon::{bon, builder, Builder};
+use bon::{bon, Builder};
+use futures::Stream;
 
 use super::types::{
-    ads::{SPAd, SPAdCreate, SPAdMultiStatusSuccess},
+    ads::{SPAd, SPAdCreate, SPAdMultiStatusSuccess, SPAdUpdate},
     enums::SPAdStateFilter,
 };
-use crate::ads_v1::types::ErrorsIndex;
+use crate::ads_v1::types::{Error, ErrorsIndex};
 use serde_with::skip_serializing_none;
 use std::sync::Arc;
 
@@ -20,24 +25,44 @@ use std::sync::Arc;
 pub struct ListAds {
     ads_client: Arc<AdsClient>,
     filter: ListAdsFilter,
+    // stream/collect_all 合计最多返回的条数, 不设置时遍历所有页
+    limit: Option<usize>,
 }
 #[bon]
 impl ListAds {
     #[builder]
-    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListAdsResponse> {
-        let mut filter = serde_json::to_value(&self.filter)?;
-        if let Some(next_token) = next_token {
-            filter["nextToken"] = json!(next_token);
-        }
-        let response = self
-            .ads_client
-            .post()
-            .path("/adsApi/v1/query/ads")
-            .json_body(filter)
-            .call()
-            .await?;
-        let data = response.json::<ListAdsResponse>().await?;
-        Ok(data)
+    pub async fn fetch(&self, next_token: Option<&str>) -> Result<ListAdsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+
+    /// 自动跟随 nextToken 遍历所有页
+    pub fn stream(&self) -> impl Stream<Item = Result<SPAd>> + '_ {
+        paginate(self.limit, move |next_token| async move {
+            let res = self
+                .fetch()
+                .maybe_next_token(next_token.as_deref())
+                .call()
+                .await?;
+            Ok((res.ads.unwrap_or_default(), res.next_token))
+        })
+    }
+
+    pub async fn collect_all(&self) -> Collected<SPAd> {
+        collect_all(self.stream()).await
+    }
+}
+
+impl AdsOperation for ListAds {
+    type Response = ListAdsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/query/ads".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
     }
 }
 
@@ -51,18 +76,35 @@ pub struct DelAds {
 
 impl DelAds {
     pub async fn fetch(self) -> Result<OperationAdsResponse> {
-        let json_body = json!({
-            "adIds": self.ad_ids
-        });
-        let res = self
-            .ads_client
-            .post()
-            .path("/adsApi/v1/delete/ads")
-            .json_body(json_body)
-            .call()
-            .await?;
-        let data = res.json::<OperationAdsResponse>().await?;
-        Ok(data)
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for DelAds {
+    type Response = OperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/delete/ads".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({ "adIds": self.ad_ids })))
+    }
+}
+
+impl BatchOperation for DelAds {
+    type Input = String;
+    type Item = SPAd;
+    type Error = Error;
+
+    const MAX_BATCH_SIZE: usize = 1000;
+
+    fn into_parts(self) -> (Arc<AdsClient>, Vec<String>) {
+        (self.ads_client, self.ad_ids)
+    }
+
+    fn from_parts(ads_client: Arc<AdsClient>, ad_ids: Vec<String>) -> Self {
+        Self { ads_client, ad_ids }
     }
 }
 
@@ -75,17 +117,7 @@ pub struct CreateAds {
 #[bon]
 impl CreateAds {
     pub async fn fetch(self) -> Result<OperationAdsResponse> {
-        let json_body = json!({
-            "ads": self.ads
-        });
-        let res = self
-            .ads_client
-            .post()
-            .path("/adsApi/v1/create/ads")
-            .json_body(json_body)
-            .call()
-            .await?;
-        Ok(res.json::<OperationAdsResponse>().await?)
+        self.ads_client.execute(&self).await
     }
 
     #[builder]
@@ -103,6 +135,75 @@ impl CreateAds {
     }
 }
 
+impl AdsOperation for CreateAds {
+    type Response = OperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/create/ads".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({ "ads": self.ads })))
+    }
+}
+
+impl BatchOperation for CreateAds {
+    type Input = SPAdCreate;
+    type Item = SPAd;
+    type Error = Error;
+
+    const MAX_BATCH_SIZE: usize = 1000;
+
+    fn into_parts(self) -> (Arc<AdsClient>, Vec<SPAdCreate>) {
+        (self.ads_client, self.ads)
+    }
+
+    fn from_parts(ads_client: Arc<AdsClient>, ads: Vec<SPAdCreate>) -> Self {
+        Self { ads_client, ads }
+    }
+}
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct UpdateAds {
+    ads_client: Arc<AdsClient>,
+    ads: Vec<SPAdUpdate>,
+}
+
+impl UpdateAds {
+    pub async fn fetch(self) -> Result<OperationAdsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateAds {
+    type Response = OperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/update/ads".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({ "ads": self.ads })))
+    }
+}
+
+impl BatchOperation for UpdateAds {
+    type Input = SPAdUpdate;
+    type Item = SPAd;
+    type Error = Error;
+
+    const MAX_BATCH_SIZE: usize = 1000;
+
+    fn into_parts(self) -> (Arc<AdsClient>, Vec<SPAdUpdate>) {
+        (self.ads_client, self.ads)
+    }
+
+    fn from_parts(ads_client: Arc<AdsClient>, ads: Vec<SPAdUpdate>) -> Self {
+        Self { ads_client, ads }
+    }
+}
+
 // region ListAdsFilter
 
 #[skip_serializing_none]
@@ -148,3 +249,19 @@ pub struct OperationAdsResponse {
     pub error: Option<Vec<ErrorsIndex>>,
     pub success: Option<Vec<SPAdMultiStatusSuccess>>,
 }
+
+impl From<OperationAdsResponse> for MultiStatus<SPAd> {
+    fn from(res: OperationAdsResponse) -> Self {
+        MultiStatus::from_parts(
+            res.success
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index as usize, item.ad)),
+            [],
+            res.error
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index as usize, item.errors)),
+        )
+    }
+}
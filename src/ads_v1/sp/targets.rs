@@ -1,12 +1,19 @@
+use crate::ads_v1::types::Error;
+use crate::batch::BatchOperation;
 use crate::client::AdsClient;
+use crate::operation::AdsOperation;
+use crate::pagination::{collect_all, paginate, Collected};
 use crate::util::{wrap_include, wrap_include_optional};
 use serde::Serialize;
-use serde_json::json;
+use serde_json::{json, Value};
 
 use super::types::enums::SPTargetType;
-use super::types::targets::{ListSPTargesResponse, OperationTargetResponse, SPTargetUpdate};
+use super::types::targets::{
+    ListSPTargesResponse, OperationTargetResponse, SPTarget, SPTargetUpdate,
+};
 use anyhow::Result;
-use bon::{bon, builder, Builder};
+use bon::{bon, Builder};
+use futures::Stream;
 use serde_with::skip_serializing_none;
 use std::sync::Arc;
 
@@ -15,26 +22,45 @@ use std::sync::Arc;
 pub struct ListTargets {
     ads_client: Arc<AdsClient>,
     filter: ListTargetsFilter,
+    // stream/collect_all 合计最多返回的条数, 不设置时遍历所有页
+    limit: Option<usize>,
 }
 
 #[bon]
 impl ListTargets {
     #[builder]
-    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListSPTargesResponse> {
-        let mut filter = serde_json::to_value(&self.filter)?;
-        if let Some(next_token) = next_token {
-            filter["nextToken"] = json!(next_token);
-        }
+    pub async fn fetch(&self, next_token: Option<&str>) -> Result<ListSPTargesResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+
+    /// 自动跟随 nextToken 遍历所有页
+    pub fn stream(&self) -> impl Stream<Item = Result<SPTarget>> + '_ {
+        paginate(self.limit, move |next_token| async move {
+            let res = self
+                .fetch()
+                .maybe_next_token(next_token.as_deref())
+                .call()
+                .await?;
+            Ok((res.targets.unwrap_or_default(), res.next_token))
+        })
+    }
+
+    pub async fn collect_all(&self) -> Collected<SPTarget> {
+        collect_all(self.stream()).await
+    }
+}
+
+impl AdsOperation for ListTargets {
+    type Response = ListSPTargesResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/query/targets".to_string()
+    }
 
-        let response = self
-            .ads_client
-            .post()
-            .path("/adsApi/v1/query/targets")
-            .json_body(filter)
-            .call()
-            .await?;
-        let data = response.json::<ListSPTargesResponse>().await?;
-        Ok(data)
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
     }
 }
 
@@ -71,16 +97,37 @@ pub struct UpdateTarget {
 
 impl UpdateTarget {
     pub async fn fetch(self) -> Result<OperationTargetResponse> {
-        let json_body = json!({
-            "targets": self.targets
-        });
-        let res = self
-            .ads_client
-            .post()
-            .path("/adsApi/v1/update/targets")
-            .json_body(json_body)
-            .call()
-            .await?;
-        Ok(res.json::<OperationTargetResponse>().await?)
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateTarget {
+    type Response = OperationTargetResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/update/targets".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({ "targets": self.targets })))
+    }
+}
+
+impl BatchOperation for UpdateTarget {
+    type Input = SPTargetUpdate;
+    type Item = SPTarget;
+    type Error = Error;
+
+    const MAX_BATCH_SIZE: usize = 1000;
+
+    fn into_parts(self) -> (Arc<AdsClient>, Vec<SPTargetUpdate>) {
+        (self.ads_client, self.targets)
+    }
+
+    fn from_parts(ads_client: Arc<AdsClient>, targets: Vec<SPTargetUpdate>) -> Self {
+        Self {
+            ads_client,
+            targets,
+        }
     }
 }
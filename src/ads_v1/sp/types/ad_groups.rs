@@ -0,0 +1,119 @@
+use super::ads::{SPCreateTag, SPStatus, SPTag};
+use super::enums::{SPCreateState, SPCurrencyCode, SPMarketplace, SPState, SPUpdateState};
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::str::FromStr;
+
+// region SPAdGroup
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPAdGroup {
+    pub ad_group_id: String,
+    pub ad_product: String,
+    pub bid: Option<SPAdGroupBid>,
+    pub campaign_id: String,
+
+    #[serde(rename = "creationDateTime")]
+    pub creation_datetime: DateTime<Utc>,
+    pub global_ad_group_id: Option<String>,
+
+    #[serde(rename = "lastUpdatedDateTime")]
+    pub last_updated_datetime: Option<DateTime<Utc>>,
+    pub marketplace_scope: String,
+    pub marketplaces: Vec<SPMarketplace>,
+    pub name: String,
+    pub state: SPState,
+    pub status: Option<SPStatus>,
+    pub tags: Option<Vec<SPTag>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPAdGroupBid {
+    pub currency_code: Option<SPCurrencyCode>,
+    pub default_bid: f64,
+}
+
+// endregion
+
+// region SPAdGroupCreate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SPAdGroupCreate {
+    #[builder(start_fn)]
+    pub campaign_id: String,
+
+    #[builder(field)]
+    pub bid: Option<SPCreateAdGroupBid>,
+
+    #[builder(default = "SPONSORED_PRODUCTS")]
+    pub ad_product: String,
+
+    pub name: String,
+
+    #[builder(default=SPCreateState::Enabled)]
+    pub state: SPCreateState,
+    pub tags: Option<Vec<SPCreateTag>>,
+}
+
+impl<S: s_p_ad_group_create_builder::State> SPAdGroupCreateBuilder<S> {
+    pub fn default_bid(mut self, default_bid: f64) -> Self {
+        self.bid = Some(SPCreateAdGroupBid { default_bid });
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCreateAdGroupBid {
+    pub default_bid: f64,
+}
+
+// endregion
+
+// region SPAdGroupUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SPAdGroupUpdate {
+    #[builder(start_fn)]
+    pub ad_group_id: String,
+
+    #[builder(field)]
+    pub bid: Option<SPCreateAdGroupBid>,
+
+    #[builder(field)]
+    pub state: Option<SPUpdateState>,
+
+    pub name: Option<String>,
+    pub tags: Option<Vec<SPCreateTag>>,
+}
+
+impl<S: s_p_ad_group_update_builder::State> SPAdGroupUpdateBuilder<S> {
+    pub fn default_bid(mut self, default_bid: f64) -> Self {
+        self.bid = Some(SPCreateAdGroupBid { default_bid });
+        self
+    }
+
+    pub fn state(mut self, state: &str) -> Self {
+        let state = SPUpdateState::from_str(state).unwrap();
+        self.state = Some(state);
+        self
+    }
+}
+
+// endregion
+
+// region SPAdGroupMultiStatusSuccess
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPAdGroupMultiStatusSuccess {
+    pub ad_group: SPAdGroup,
+    pub index: usize,
+}
+// endregion
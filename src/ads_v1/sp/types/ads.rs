@@ -1,11 +1,13 @@
 use super::enums::{
     SPCreateState, SPDeliveryReason, SPDeliveryStatus, SPMarketplace, SPProductIdType, SPState,
+    SPUpdateState,
 };
 use bon::Builder;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_inline_default::serde_inline_default;
 use serde_with::skip_serializing_none;
+use std::str::FromStr;
 // region SPAd 相关
 #[serde_inline_default]
 #[derive(Debug, Deserialize)]
@@ -93,6 +95,73 @@ impl<S: s_p_ad_create_builder::State> SPAdCreateBuilder<S> {
 
 // endregion
 
+// region SPAdUpdate
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SPAdUpdate {
+    #[builder(start_fn)]
+    pub ad_id: String,
+    #[builder(field)]
+    pub creative: Option<SPUpdateCreative>,
+    #[builder(field)]
+    pub state: Option<SPUpdateState>,
+    pub tags: Option<Vec<SPCreateTag>>,
+}
+
+impl<S: s_p_ad_update_builder::State> SPAdUpdateBuilder<S> {
+    pub fn state(mut self, state: &str) -> Self {
+        let state = SPUpdateState::from_str(state).unwrap();
+        self.state = Some(state);
+        self
+    }
+
+    pub fn headline(mut self, headline: &str) -> Self {
+        self.creative_settings().headline = Some(headline.to_string());
+        self
+    }
+
+    pub fn spotlight_videos(mut self, optimize_text: bool, videos: Vec<SPCreateVideo>) -> Self {
+        self.creative_settings().spotlight_videos = Some(SPCreateSpotlightVideoSettings {
+            optimize_text,
+            videos,
+        });
+        self
+    }
+
+    fn creative_settings(&mut self) -> &mut SPUpdateProductCreativeSettings {
+        &mut self
+            .creative
+            .get_or_insert_with(Default::default)
+            .product_creative
+            .product_creative_settings
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPUpdateCreative {
+    pub product_creative: SPUpdateProductCreative,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPUpdateProductCreative {
+    pub product_creative_settings: SPUpdateProductCreativeSettings,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPUpdateProductCreativeSettings {
+    pub headline: Option<String>,
+    pub spotlight_videos: Option<SPCreateSpotlightVideoSettings>,
+}
+
+// endregion
+
 // region SPCreative
 
 #[derive(Debug, Deserialize)]
@@ -207,7 +276,9 @@ pub struct SPVideo {
     pub headline: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
 #[serde(rename_all = "camelCase")]
 pub struct SPCreateVideo {
     pub asset_id: String,
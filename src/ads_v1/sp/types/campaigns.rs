@@ -0,0 +1,324 @@
+use super::ads::{SPCreateTag, SPStatus, SPTag};
+use super::enums::{
+    SPBidStrategy, SPBudgetType, SPCreateState, SPCurrencyCode, SPMarketplace, SPPlacement,
+    SPRecurrenceTimePeriod, SPState, SPUpdateState,
+};
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::str::FromStr;
+
+// region SPCampaign
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCampaign {
+    pub ad_product: String,
+    pub auto_creation_settings: Option<SPAutoCreationSettings>,
+    pub budgets: Option<Vec<SPBudget>>,
+    pub campaign_id: String,
+
+    #[serde(rename = "creationDateTime")]
+    pub creation_datetime: DateTime<Utc>,
+
+    #[serde(rename = "endDateTime")]
+    pub end_datetime: Option<DateTime<Utc>>,
+    pub global_campaign_id: Option<String>,
+
+    #[serde(rename = "lastUpdatedDateTime")]
+    pub last_updated_datetime: Option<DateTime<Utc>>,
+    pub marketplace_scope: String,
+    pub marketplaces: Vec<SPMarketplace>,
+    pub name: String,
+    pub optimizations: Option<SPCampaignOptimizations>,
+    pub portfolio_id: Option<String>,
+
+    #[serde(rename = "startDateTime")]
+    pub start_datetime: Option<DateTime<Utc>>,
+    pub state: SPState,
+    pub status: Option<SPStatus>,
+    pub tags: Option<Vec<SPTag>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPAutoCreationSettings {
+    pub auto_create_targets: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPBudget {
+    pub budget_type: SPBudgetType,
+    pub budget_value: SPBudgetValue,
+    pub recurrence_time_period: SPRecurrenceTimePeriod,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPBudgetValue {
+    pub monetary_budget_value: Option<SPMonetaryBudgetValue>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPMonetaryBudgetValue {
+    pub monetary_budget: SPMonetaryBudget,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPMonetaryBudget {
+    pub currency_code: Option<SPCurrencyCode>,
+    pub value: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCampaignOptimizations {
+    pub bid_settings: Option<SPBidSettings>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPBidSettings {
+    pub bid_adjustments: Option<SPBidAdjustments>,
+    pub bid_strategy: Option<SPBidStrategy>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPBidAdjustments {
+    pub placement_bid_adjustments: Option<Vec<SPPlacementBidAdjustment>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPPlacementBidAdjustment {
+    pub percentage: i32,
+    pub placement: SPPlacement,
+}
+
+// endregion
+
+// region SPCampaignCreate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SPCampaignCreate {
+    #[builder(start_fn)]
+    pub name: String,
+
+    #[builder(field)]
+    pub auto_creation_settings: SPCreateAutoCreationSettings,
+
+    #[builder(field)]
+    pub budgets: Vec<SPCreateBudget>,
+
+    #[builder(field)]
+    pub optimizations: Option<SPCreateCampaignOptimizations>,
+
+    #[builder(default = "SPONSORED_PRODUCTS")]
+    pub ad_product: String,
+
+    #[serde(rename = "endDateTime")]
+    pub end_datetime: Option<DateTime<Utc>>,
+
+    pub portfolio_id: Option<String>,
+
+    #[serde(rename = "startDateTime")]
+    pub start_datetime: Option<DateTime<Utc>>,
+
+    #[builder(default=SPCreateState::Enabled)]
+    pub state: SPCreateState,
+    pub tags: Option<Vec<SPCreateTag>>,
+}
+
+impl<S: s_p_campaign_create_builder::State> SPCampaignCreateBuilder<S> {
+    /// true 为自动投放广告活动, false 为手动投放
+    pub fn auto_targeting(mut self, auto_create_targets: bool) -> Self {
+        self.auto_creation_settings = SPCreateAutoCreationSettings {
+            auto_create_targets,
+        };
+        self
+    }
+
+    pub fn daily_budget(mut self, budget: f64) -> Self {
+        self.budgets = vec![SPCreateBudget::daily(budget)];
+        self
+    }
+
+    pub fn bid_strategy(mut self, bid_strategy: SPBidStrategy) -> Self {
+        self.optimizations
+            .get_or_insert_with(Default::default)
+            .bid_settings
+            .bid_strategy = Some(bid_strategy);
+        self
+    }
+
+    pub fn placement_bid_adjustment(mut self, placement: SPPlacement, percentage: i32) -> Self {
+        self.optimizations
+            .get_or_insert_with(Default::default)
+            .bid_settings
+            .push_placement(placement, percentage);
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCreateAutoCreationSettings {
+    pub auto_create_targets: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCreateBudget {
+    pub budget_type: SPBudgetType,
+    pub budget_value: SPCreateBudgetValue,
+    pub recurrence_time_period: SPRecurrenceTimePeriod,
+}
+
+impl SPCreateBudget {
+    pub fn daily(value: f64) -> Self {
+        Self {
+            budget_type: SPBudgetType::Monetary,
+            budget_value: SPCreateBudgetValue {
+                monetary_budget_value: SPCreateMonetaryBudgetValue {
+                    monetary_budget: SPCreateMonetaryBudget { value },
+                },
+            },
+            recurrence_time_period: SPRecurrenceTimePeriod::Daily,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCreateBudgetValue {
+    pub monetary_budget_value: SPCreateMonetaryBudgetValue,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCreateMonetaryBudgetValue {
+    pub monetary_budget: SPCreateMonetaryBudget,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCreateMonetaryBudget {
+    pub value: f64,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCreateCampaignOptimizations {
+    pub bid_settings: SPCreateBidSettings,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCreateBidSettings {
+    pub bid_adjustments: Option<SPCreateBidAdjustments>,
+    pub bid_strategy: Option<SPBidStrategy>,
+}
+
+impl SPCreateBidSettings {
+    fn push_placement(&mut self, placement: SPPlacement, percentage: i32) {
+        self.bid_adjustments
+            .get_or_insert_with(|| SPCreateBidAdjustments {
+                placement_bid_adjustments: vec![],
+            })
+            .placement_bid_adjustments
+            .push(SPCreatePlacementBidAdjustment {
+                percentage,
+                placement,
+            });
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCreateBidAdjustments {
+    pub placement_bid_adjustments: Vec<SPCreatePlacementBidAdjustment>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCreatePlacementBidAdjustment {
+    pub percentage: i32,
+    pub placement: SPPlacement,
+}
+
+// endregion
+
+// region SPCampaignUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SPCampaignUpdate {
+    #[builder(start_fn)]
+    pub campaign_id: String,
+
+    #[builder(field)]
+    pub budgets: Option<Vec<SPCreateBudget>>,
+
+    #[builder(field)]
+    pub optimizations: Option<SPCreateCampaignOptimizations>,
+
+    #[builder(field)]
+    pub state: Option<SPUpdateState>,
+
+    #[serde(rename = "endDateTime")]
+    pub end_datetime: Option<DateTime<Utc>>,
+    pub name: Option<String>,
+    pub portfolio_id: Option<String>,
+
+    #[serde(rename = "startDateTime")]
+    pub start_datetime: Option<DateTime<Utc>>,
+    pub tags: Option<Vec<SPCreateTag>>,
+}
+
+impl<S: s_p_campaign_update_builder::State> SPCampaignUpdateBuilder<S> {
+    pub fn state(mut self, state: &str) -> Self {
+        let state = SPUpdateState::from_str(state).unwrap();
+        self.state = Some(state);
+        self
+    }
+
+    pub fn daily_budget(mut self, budget: f64) -> Self {
+        self.budgets = Some(vec![SPCreateBudget::daily(budget)]);
+        self
+    }
+
+    pub fn bid_strategy(mut self, bid_strategy: SPBidStrategy) -> Self {
+        self.optimizations
+            .get_or_insert_with(Default::default)
+            .bid_settings
+            .bid_strategy = Some(bid_strategy);
+        self
+    }
+
+    pub fn placement_bid_adjustment(mut self, placement: SPPlacement, percentage: i32) -> Self {
+        self.optimizations
+            .get_or_insert_with(Default::default)
+            .bid_settings
+            .push_placement(placement, percentage);
+        self
+    }
+}
+
+// endregion
+
+// region SPCampaignMultiStatusSuccess
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPCampaignMultiStatusSuccess {
+    pub campaign: SPCampaign,
+    pub index: usize,
+}
+// endregion
@@ -336,3 +336,56 @@ pub enum SPThemeMatchType {
     ProductComplements,                   // 与广告产品互补的产品。
     ProductSubstitutes,                   // 可替代广告宣传产品的其他产品。
 }
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPBudgetType {
+    Monetary,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPRecurrenceTimePeriod {
+    Daily,
+    Lifetime,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPBidStrategy {
+    Manual,         // 固定竞价
+    SalesDownOnly,  // 动态竞价 - 仅降低
+    SalesUpAndDown, // 动态竞价 - 提高和降低
+    RuleBased,      // 基于规则的竞价
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPPlacement {
+    TopOfSearch,        // 搜索结果顶部(首页)
+    RestOfSearch,       // 搜索结果其余位置
+    ProductPage,        // 商品详情页
+    SiteAmazonBusiness, // Amazon Business
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPCampaignStateFilter {
+    Enabled,
+    Paused,
+    Archived,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPAdGroupStateFilter {
+    Enabled,
+    Paused,
+    Archived,
+}
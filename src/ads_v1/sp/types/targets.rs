@@ -4,6 +4,7 @@ use super::enums::{
     SPUpdateState,
 };
 use crate::ads_v1::types::ErrorsIndex;
+use crate::multi_status::MultiStatus;
 use bon::Builder;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -195,6 +196,22 @@ pub struct OperationTargetResponse {
     pub success: Option<Vec<SPTargetMultiStatusSuccess>>,
 }
 
+impl From<OperationTargetResponse> for MultiStatus<SPTarget> {
+    fn from(res: OperationTargetResponse) -> Self {
+        MultiStatus::from_parts(
+            res.success
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index, item.target)),
+            [],
+            res.error
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index as usize, item.errors)),
+        )
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SPTargetMultiStatusSuccess {
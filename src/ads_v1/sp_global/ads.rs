@@ -1,15 +1,21 @@
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{json, Value};
 
-use crate::ads_v1::types::ErrorsIndex;
+use crate::ads_v1::types::{Error, ErrorsIndex};
+use crate::batch::BatchOperation;
 use crate::client::AdsClient;
+use crate::multi_status::MultiStatus;
+use crate::operation::AdsOperation;
 use crate::util::{wrap_include, wrap_include_optional};
 use anyhow::Result;
 use bon::{bon, Builder};
 
 use super::types::enums::SPGlobalMarketplace;
 use super::types::{
-    ads::{SPGlobalAd, SPGlobalAdCreate, SPGlobalAdMultiStatusSuccess, SPGlobalAdPartialIndex},
+    ads::{
+        SPGlobalAd, SPGlobalAdCreate, SPGlobalAdMultiStatusSuccess, SPGlobalAdPartialIndex,
+        SPGlobalAdUpdate,
+    },
     enums::SPGlobalAdStateFilter,
 };
 use serde_with::skip_serializing_none;
@@ -26,20 +32,22 @@ pub struct ListAds {
 #[bon]
 impl ListAds {
     #[builder]
-    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListAdsResponse> {
-        let mut filter = serde_json::to_value(&self.filter)?;
-        if let Some(next_token) = next_token {
-            filter["nextToken"] = json!(next_token);
-        }
-        let response = self
-            .ads_client
-            .post()
-            .path("/adsApi/v1/query/ads")
-            .json_body(filter)
-            .call()
-            .await?;
-        let data = response.json::<ListAdsResponse>().await?;
-        Ok(data)
+    pub async fn fetch(&self, next_token: Option<&str>) -> Result<ListAdsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+}
+
+impl AdsOperation for ListAds {
+    type Response = ListAdsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/query/ads".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
     }
 }
 
@@ -56,17 +64,7 @@ pub struct CreateAds {
 #[bon]
 impl CreateAds {
     pub async fn fetch(self) -> Result<OperationAdsResponse> {
-        let json_body = json!({
-            "ads": self.ads
-        });
-        let res = self
-            .ads_client
-            .post()
-            .path("/adsApi/v1/create/ads")
-            .json_body(json_body)
-            .call()
-            .await?;
-        Ok(res.json::<OperationAdsResponse>().await?)
+        self.ads_client.execute(&self).await
     }
 
     #[builder]
@@ -112,6 +110,123 @@ impl CreateAds {
     }
 }
 
+impl AdsOperation for CreateAds {
+    type Response = OperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/create/ads".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({ "ads": self.ads })))
+    }
+}
+
+impl BatchOperation for CreateAds {
+    type Input = SPGlobalAdCreate;
+    type Item = SPGlobalAd;
+    type Error = Error;
+
+    const MAX_BATCH_SIZE: usize = 1000;
+
+    fn into_parts(self) -> (Arc<AdsClient>, Vec<SPGlobalAdCreate>) {
+        (self.ads_client, self.ads)
+    }
+
+    fn from_parts(ads_client: Arc<AdsClient>, ads: Vec<SPGlobalAdCreate>) -> Self {
+        Self { ads_client, ads }
+    }
+}
+
+// endregion
+
+// region UpdateAds
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct UpdateAds {
+    ads_client: Arc<AdsClient>,
+    ads: Vec<SPGlobalAdUpdate>,
+}
+
+impl UpdateAds {
+    pub async fn fetch(self) -> Result<OperationAdsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateAds {
+    type Response = OperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/update/ads".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({ "ads": self.ads })))
+    }
+}
+
+impl BatchOperation for UpdateAds {
+    type Input = SPGlobalAdUpdate;
+    type Item = SPGlobalAd;
+    type Error = Error;
+
+    const MAX_BATCH_SIZE: usize = 1000;
+
+    fn into_parts(self) -> (Arc<AdsClient>, Vec<SPGlobalAdUpdate>) {
+        (self.ads_client, self.ads)
+    }
+
+    fn from_parts(ads_client: Arc<AdsClient>, ads: Vec<SPGlobalAdUpdate>) -> Self {
+        Self { ads_client, ads }
+    }
+}
+
+// endregion
+
+// region DelAds
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct DelAds {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    ad_ids: Vec<String>,
+}
+
+impl DelAds {
+    pub async fn fetch(self) -> Result<OperationAdsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for DelAds {
+    type Response = OperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/delete/ads".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({ "adIds": self.ad_ids })))
+    }
+}
+
+impl BatchOperation for DelAds {
+    type Input = String;
+    type Item = SPGlobalAd;
+    type Error = Error;
+
+    const MAX_BATCH_SIZE: usize = 1000;
+
+    fn into_parts(self) -> (Arc<AdsClient>, Vec<String>) {
+        (self.ads_client, self.ad_ids)
+    }
+
+    fn from_parts(ads_client: Arc<AdsClient>, ad_ids: Vec<String>) -> Self {
+        Self { ads_client, ad_ids }
+    }
+}
+
 // endregion
 
 // region ListAdsFilter
@@ -161,3 +276,22 @@ pub struct OperationAdsResponse {
     pub partial_success: Option<Vec<SPGlobalAdPartialIndex>>,
     pub success: Option<Vec<SPGlobalAdMultiStatusSuccess>>,
 }
+
+impl From<OperationAdsResponse> for MultiStatus<SPGlobalAd> {
+    fn from(res: OperationAdsResponse) -> Self {
+        MultiStatus::from_parts(
+            res.success
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index as usize, item.ad)),
+            res.partial_success
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index as usize, item.ad, item.errors)),
+            res.error
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index as usize, item.errors)),
+        )
+    }
+}
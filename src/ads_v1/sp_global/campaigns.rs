@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::AdsOperation;
+use crate::util::{wrap_include, wrap_include_optional};
+
+use anyhow::Result;
+use bon::{bon, Builder};
+
+use super::types::{
+    campaigns::{
+        SPGlobalCampaign, SPGlobalCampaignCreate, SPGlobalCampaignMultiStatusSuccess,
+        SPGlobalCampaignPartialIndex, SPGlobalCampaignUpdate,
+    },
+    enums::SPGlobalCampaignStateFilter,
+};
+use crate::ads_v1::types::ErrorsIndex;
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+
+// region ListCampaigns
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListCampaigns {
+    ads_client: Arc<AdsClient>,
+    filter: ListCampaignsFilter,
+}
+
+#[bon]
+impl ListCampaigns {
+    #[builder]
+    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListCampaignsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+}
+
+impl AdsOperation for ListCampaigns {
+    type Response = ListCampaignsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/query/campaigns".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Builder, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListCampaignsFilter {
+    #[serde(serialize_with = "wrap_include")]
+    #[builder(default = vec!["SPONSORED_PRODUCTS".to_string()])]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_product_filter: Vec<String>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub campaign_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub name_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub portfolio_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include")]
+    #[builder(default = vec!["GLOBAL".to_string()])]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub marketplace_scope_filter: Vec<String>,
+
+    #[builder(default = 1000)]
+    max_results: i32,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    state_filter: Option<Vec<SPGlobalCampaignStateFilter>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListCampaignsResponse {
+    pub campaigns: Option<Vec<SPGlobalCampaign>>,
+    pub next_token: Option<String>,
+}
+
+// endregion
+
+// region CreateCampaigns
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct CreateCampaigns {
+    ads_client: Arc<AdsClient>,
+    campaigns: Vec<SPGlobalCampaignCreate>,
+}
+
+impl CreateCampaigns {
+    pub async fn fetch(self) -> Result<OperationCampaignsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateCampaigns {
+    type Response = OperationCampaignsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/create/campaigns".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "campaigns": self.campaigns
+        })))
+    }
+}
+
+// endregion
+
+// region UpdateCampaigns
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct UpdateCampaigns {
+    ads_client: Arc<AdsClient>,
+    campaigns: Vec<SPGlobalCampaignUpdate>,
+}
+
+impl UpdateCampaigns {
+    pub async fn fetch(self) -> Result<OperationCampaignsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateCampaigns {
+    type Response = OperationCampaignsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/update/campaigns".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "campaigns": self.campaigns
+        })))
+    }
+}
+
+// endregion
+
+// region DelCampaigns
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct DelCampaigns {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    campaign_ids: Vec<String>,
+}
+
+impl DelCampaigns {
+    pub async fn fetch(self) -> Result<OperationCampaignsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for DelCampaigns {
+    type Response = OperationCampaignsResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/delete/campaigns".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "campaignIds": self.campaign_ids
+        })))
+    }
+}
+
+// endregion
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationCampaignsResponse {
+    pub error: Option<Vec<ErrorsIndex>>,
+    pub partial_success: Option<Vec<SPGlobalCampaignPartialIndex>>,
+    pub success: Option<Vec<SPGlobalCampaignMultiStatusSuccess>>,
+}
@@ -1,14 +1,17 @@
 use crate::client::AdsClient;
+use crate::operation::AdsOperation;
+use crate::pagination::{collect_all, paginate, Collected};
 use crate::util::{wrap_include, wrap_include_optional};
 use serde::Serialize;
-use serde_json::json;
+use serde_json::{json, Value};
 
 use super::types::enums::SPGlobalTargetType;
 use super::types::targets::{
-    ListSPGlobalTargesResponse, OperationGlobalTargetResponse, SPGlobalTargetUpdate,
+    ListSPGlobalTargesResponse, OperationGlobalTargetResponse, SPGlobalTarget, SPGlobalTargetUpdate,
 };
 use anyhow::Result;
-use bon::{bon, builder, Builder};
+use bon::{bon, Builder};
+use futures::Stream;
 use serde_with::skip_serializing_none;
 use std::sync::Arc;
 
@@ -17,26 +20,45 @@ use std::sync::Arc;
 pub struct ListGlobalTargets {
     ads_client: Arc<AdsClient>,
     filter: ListGlobalTargetsFilter,
+    // stream/collect_all 合计最多返回的条数, 不设置时遍历所有页
+    limit: Option<usize>,
 }
 
 #[bon]
 impl ListGlobalTargets {
     #[builder]
-    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListSPGlobalTargesResponse> {
-        let mut filter = serde_json::to_value(&self.filter)?;
-        if let Some(next_token) = next_token {
-            filter["nextToken"] = json!(next_token);
-        }
-
-        let response = self
-            .ads_client
-            .post()
-            .path("/adsApi/v1/query/targets")
-            .json_body(filter)
-            .call()
-            .await?;
-        let data = response.json::<ListSPGlobalTargesResponse>().await?;
-        Ok(data)
+    pub async fn fetch(&self, next_token: Option<&str>) -> Result<ListSPGlobalTargesResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+
+    /// 自动跟随 nextToken 遍历所有页
+    pub fn stream(&self) -> impl Stream<Item = Result<SPGlobalTarget>> + '_ {
+        paginate(self.limit, move |next_token| async move {
+            let res = self
+                .fetch()
+                .maybe_next_token(next_token.as_deref())
+                .call()
+                .await?;
+            Ok((res.targets.unwrap_or_default(), res.next_token))
+        })
+    }
+
+    pub async fn collect_all(&self) -> Collected<SPGlobalTarget> {
+        collect_all(self.stream()).await
+    }
+}
+
+impl AdsOperation for ListGlobalTargets {
+    type Response = ListSPGlobalTargesResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/query/targets".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
     }
 }
 
@@ -77,16 +99,18 @@ pub struct UpdateGlobalTarget {
 
 impl UpdateGlobalTarget {
     pub async fn fetch(self) -> Result<OperationGlobalTargetResponse> {
-        let json_body = json!({
-            "targets": self.targets
-        });
-        let res = self
-            .ads_client
-            .post()
-            .path("/adsApi/v1/update/targets")
-            .json_body(json_body)
-            .call()
-            .await?;
-        Ok(res.json::<OperationGlobalTargetResponse>().await?)
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateGlobalTarget {
+    type Response = OperationGlobalTargetResponse;
+
+    fn path(&self) -> String {
+        "/adsApi/v1/update/targets".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({ "targets": self.targets })))
     }
 }
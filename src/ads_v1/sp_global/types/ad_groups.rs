@@ -0,0 +1,187 @@
+use super::ads::{SPGlobalCreateTag, SPGlobalStatus, SPGlobalTag};
+use super::enums::{
+    SPGlobalCreateState, SPGlobalCurrencyCode, SPGlobalMarketplace, SPGlobalState,
+    SPGlobalUpdateState,
+};
+use crate::ads_v1::types::Error;
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::str::FromStr;
+
+// region SPGlobalAdGroup
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalAdGroup {
+    pub ad_group_id: String,
+    pub ad_product: String,
+    pub bid: Option<SPGlobalAdGroupBid>,
+    pub campaign_id: String,
+
+    #[serde(rename = "creationDateTime")]
+    pub creation_datetime: DateTime<Utc>,
+
+    #[serde(rename = "lastUpdatedDateTime")]
+    pub last_updated_datetime: Option<DateTime<Utc>>,
+    pub marketplace_configurations: Option<Vec<SPGlobalMarketplaceAdGroupConfigurations>>,
+    pub marketplace_scope: String,
+    pub marketplaces: Vec<SPGlobalMarketplace>,
+    pub name: String,
+    pub state: SPGlobalState,
+    pub status: Option<SPGlobalStatus>,
+    pub tags: Option<Vec<SPGlobalTag>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalAdGroupBid {
+    pub marketplace_settings: Option<Vec<SPGlobalAdGroupBidMarketplaceSetting>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalAdGroupBidMarketplaceSetting {
+    pub currency_code: SPGlobalCurrencyCode,
+    pub default_bid: f64,
+    pub marketplace: SPGlobalMarketplace,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalMarketplaceAdGroupConfigurations {
+    pub ad_group_id: Option<String>,
+    pub marketplace: SPGlobalMarketplace,
+    pub overrides: SPGlobalMarketplaceAdGroupFieldOverrides,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalMarketplaceAdGroupFieldOverrides {
+    pub name: Option<String>,
+    pub state: Option<SPGlobalState>,
+    pub tags: Option<Vec<SPGlobalTag>>,
+}
+
+// endregion
+
+// region SPGlobalAdGroupCreate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalAdGroupCreate {
+    #[builder(start_fn)]
+    pub campaign_id: String,
+
+    #[builder(field)]
+    pub bid: Option<SPGlobalCreateAdGroupBid>,
+
+    #[builder(default="SPONSORED_PRODUCTS".to_string())]
+    pub ad_product: String,
+
+    #[builder(default="GLOBAL".to_string())]
+    pub marketplace_scope: String,
+
+    pub marketplaces: Vec<SPGlobalMarketplace>,
+
+    pub name: String,
+
+    #[builder(default=SPGlobalCreateState::Enabled)]
+    pub state: SPGlobalCreateState,
+
+    pub tags: Option<Vec<SPGlobalCreateTag>>,
+}
+
+impl<S: s_p_global_ad_group_create_builder::State> SPGlobalAdGroupCreateBuilder<S> {
+    /// 设置某个站点的默认竞价, 多个站点多次调用
+    pub fn default_bid(mut self, country_code: &str, default_bid: f64) -> Self {
+        self.bid
+            .get_or_insert_with(Default::default)
+            .push_marketplace(country_code, default_bid);
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateAdGroupBid {
+    pub marketplace_settings: Vec<SPGlobalCreateAdGroupBidMarketplaceSetting>,
+}
+
+impl SPGlobalCreateAdGroupBid {
+    fn push_marketplace(&mut self, country_code: &str, default_bid: f64) {
+        self.marketplace_settings
+            .push(SPGlobalCreateAdGroupBidMarketplaceSetting {
+                currency_code: SPGlobalCurrencyCode::from_country_code(country_code)
+                    .expect("无法识别的国家代码"),
+                default_bid,
+                marketplace: SPGlobalMarketplace::from_str(country_code).expect("无效的国家代码"),
+            });
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateAdGroupBidMarketplaceSetting {
+    pub currency_code: SPGlobalCurrencyCode,
+    pub default_bid: f64,
+    pub marketplace: SPGlobalMarketplace,
+}
+
+// endregion
+
+// region SPGlobalAdGroupUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalAdGroupUpdate {
+    #[builder(start_fn)]
+    pub ad_group_id: String,
+
+    #[builder(field)]
+    pub bid: Option<SPGlobalCreateAdGroupBid>,
+
+    #[builder(field)]
+    pub state: Option<SPGlobalUpdateState>,
+
+    pub marketplaces: Option<Vec<SPGlobalMarketplace>>,
+    pub name: Option<String>,
+    pub tags: Option<Vec<SPGlobalCreateTag>>,
+}
+
+impl<S: s_p_global_ad_group_update_builder::State> SPGlobalAdGroupUpdateBuilder<S> {
+    /// 设置某个站点的默认竞价, 多个站点多次调用
+    pub fn default_bid(mut self, country_code: &str, default_bid: f64) -> Self {
+        self.bid
+            .get_or_insert_with(Default::default)
+            .push_marketplace(country_code, default_bid);
+        self
+    }
+
+    pub fn state(mut self, state: &str) -> Self {
+        let state = SPGlobalUpdateState::from_str(state).unwrap();
+        self.state = Some(state);
+        self
+    }
+}
+
+// endregion
+
+// region OperationAdGroupsResponse
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalAdGroupPartialIndex {
+    pub ad_group: SPGlobalAdGroup,
+    pub errors: Option<Vec<Error>>,
+    pub index: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalAdGroupMultiStatusSuccess {
+    pub ad_group: SPGlobalAdGroup,
+    pub index: usize,
+}
+// endregion
@@ -1,9 +1,9 @@
 use super::enums::{
     SPGlobalCreateState, SPGlobalDeliveryReason, SPGlobalDeliveryStatus, SPGlobalMarketplace,
-    SPGlobalProductIdType, SPGlobalState,
+    SPGlobalProductIdType, SPGlobalState, SPGlobalUpdateState,
 };
 use crate::ads_v1::types::Error;
-use bon::{builder, Builder};
+use bon::Builder;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_inline_default::serde_inline_default;
@@ -14,7 +14,6 @@ use std::str::FromStr;
 #[serde_inline_default]
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-
 pub struct SPGlobalAd {
     pub ad_group_id: String,
     pub ad_id: String,
@@ -47,7 +46,6 @@ pub struct SPGlobalAd {
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 #[serde(rename_all = "camelCase")]
-
 pub struct SPGlobalAdCreate {
     #[builder(start_fn)]
     pub ad_group_id: String,
@@ -126,6 +124,110 @@ impl<S: s_p_global_ad_create_builder::State> SPGlobalAdCreateBuilder<S> {
 
 // endregion
 
+// region SPGlobalAdUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalAdUpdate {
+    #[builder(start_fn)]
+    pub ad_id: String,
+    #[builder(field)]
+    pub creative: Option<SPGlobalUpdateCreative>,
+    #[builder(field)]
+    pub marketplace_configurations: Option<Vec<SPGlobalCreateMarketplaceAdConfigurations>>,
+    #[builder(field)]
+    pub state: Option<SPGlobalUpdateState>,
+    pub tags: Option<Vec<SPGlobalCreateTag>>,
+}
+
+impl<S: s_p_global_ad_update_builder::State> SPGlobalAdUpdateBuilder<S> {
+    pub fn state(mut self, state: &str) -> Self {
+        let state = SPGlobalUpdateState::from_str(state).unwrap();
+        self.state = Some(state);
+        self
+    }
+
+    /// 只修改某一个站点的状态, 其余站点保持全局设置
+    pub fn marketplace_state(mut self, country_code: &str, state: SPGlobalState) -> Self {
+        self.marketplace_configurations
+            .get_or_insert_with(Vec::new)
+            .push(SPGlobalCreateMarketplaceAdConfigurations {
+                marketplace: SPGlobalMarketplace::from_str(country_code).expect("无效的国家代码"),
+                overrides: SPGlobalCreateMarketplaceAdFieldOverrides {
+                    state: Some(state),
+                    tags: None,
+                },
+            });
+        self
+    }
+
+    pub fn headline(mut self, headline: &str) -> Self {
+        self.creative_settings().headline = Some(headline.to_string());
+        self
+    }
+
+    pub fn spotlight_videos(
+        mut self,
+        optimize_text: bool,
+        videos: Vec<SPGlobalCreateVideo>,
+    ) -> Self {
+        self.creative_settings().spotlight_videos = Some(SPGlobalCreateSpotlightVideoSettings {
+            optimize_text,
+            videos,
+        });
+        self
+    }
+
+    fn creative_settings(&mut self) -> &mut SPGlobalUpdateProductCreativeSettings {
+        &mut self
+            .creative
+            .get_or_insert_with(Default::default)
+            .product_creative
+            .product_creative_settings
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalUpdateCreative {
+    pub product_creative: SPGlobalUpdateProductCreative,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalUpdateProductCreative {
+    pub product_creative_settings: SPGlobalUpdateProductCreativeSettings,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalUpdateProductCreativeSettings {
+    pub headline: Option<String>,
+    pub spotlight_videos: Option<SPGlobalCreateSpotlightVideoSettings>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateSpotlightVideoSettings {
+    pub optimize_text: bool,
+    pub videos: Vec<SPGlobalCreateVideo>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateVideo {
+    pub asset_id: String,
+    pub asset_version: String,
+    pub description: Option<String>,
+    pub headline: Option<String>,
+}
+
+// endregion
+
 // region SPGlobalCreative
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -248,6 +350,7 @@ pub struct SPGlobalCreateMarketplaceAdConfigurations {
     overrides: SPGlobalCreateMarketplaceAdFieldOverrides,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SPGlobalCreateMarketplaceAdFieldOverrides {
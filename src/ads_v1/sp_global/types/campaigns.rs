@@ -0,0 +1,487 @@
+use super::ads::{SPGlobalCreateTag, SPGlobalStatus, SPGlobalTag};
+use super::enums::{
+    SPGlobalBidStrategy, SPGlobalBudgetType, SPGlobalCreateState, SPGlobalCurrencyCode,
+    SPGlobalMarketplace, SPGlobalPlacement, SPGlobalRecurrenceTimePeriod, SPGlobalState,
+    SPGlobalUpdateState,
+};
+use crate::ads_v1::types::Error;
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::str::FromStr;
+
+// region SPGlobalCampaign
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCampaign {
+    pub ad_product: String,
+    pub auto_creation_settings: Option<SPGlobalAutoCreationSettings>,
+    pub budgets: Option<Vec<SPGlobalBudget>>,
+    pub campaign_id: String,
+
+    #[serde(rename = "creationDateTime")]
+    pub creation_datetime: DateTime<Utc>,
+
+    #[serde(rename = "endDateTime")]
+    pub end_datetime: Option<DateTime<Utc>>,
+
+    #[serde(rename = "lastUpdatedDateTime")]
+    pub last_updated_datetime: Option<DateTime<Utc>>,
+    pub marketplace_configurations: Option<Vec<SPGlobalMarketplaceCampaignConfigurations>>,
+    pub marketplace_scope: String,
+    pub marketplaces: Vec<SPGlobalMarketplace>,
+    pub name: String,
+    pub optimizations: Option<SPGlobalCampaignOptimizations>,
+    pub portfolio_id: Option<String>,
+
+    #[serde(rename = "startDateTime")]
+    pub start_datetime: Option<DateTime<Utc>>,
+    pub state: SPGlobalState,
+    pub status: Option<SPGlobalStatus>,
+    pub tags: Option<Vec<SPGlobalTag>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalAutoCreationSettings {
+    pub auto_create_targets: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalBudget {
+    pub budget_type: SPGlobalBudgetType,
+    pub budget_value: SPGlobalBudgetValue,
+    pub recurrence_time_period: SPGlobalRecurrenceTimePeriod,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalBudgetValue {
+    pub monetary_budget_value: Option<SPGlobalMonetaryBudgetValue>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalMonetaryBudgetValue {
+    pub marketplace_settings: Option<Vec<SPGlobalMonetaryBudgetMarketplaceSetting>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalMonetaryBudgetMarketplaceSetting {
+    pub marketplace: SPGlobalMarketplace,
+    pub monetary_budget: SPGlobalMonetaryBudget,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalMonetaryBudget {
+    pub currency_code: Option<SPGlobalCurrencyCode>,
+    pub value: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCampaignOptimizations {
+    pub bid_settings: Option<SPGlobalBidSettings>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalBidSettings {
+    pub bid_adjustments: Option<SPGlobalBidAdjustments>,
+    pub bid_strategy: Option<SPGlobalBidStrategy>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalBidAdjustments {
+    pub placement_bid_adjustments: Option<Vec<SPGlobalPlacementBidAdjustment>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalPlacementBidAdjustment {
+    pub percentage: i32,
+    pub placement: SPGlobalPlacement,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalMarketplaceCampaignConfigurations {
+    pub campaign_id: Option<String>,
+    pub marketplace: SPGlobalMarketplace,
+    pub overrides: SPGlobalMarketplaceCampaignFieldOverrides,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalMarketplaceCampaignFieldOverrides {
+    pub name: Option<String>,
+    pub optimizations: Option<SPGlobalCampaignOptimizations>,
+    pub state: Option<SPGlobalState>,
+    pub tags: Option<Vec<SPGlobalTag>>,
+}
+
+// endregion
+
+// region SPGlobalCampaignCreate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCampaignCreate {
+    #[builder(start_fn)]
+    pub name: String,
+
+    #[builder(field)]
+    pub auto_creation_settings: SPGlobalCreateAutoCreationSettings,
+
+    // 必填, 至少调用一次 daily_budget; 未设置时不发送, 由服务端返回错误
+    #[builder(field)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub budgets: Vec<SPGlobalCreateBudget>,
+
+    #[builder(field)]
+    pub marketplace_configurations: Option<Vec<SPGlobalCreateMarketplaceCampaignConfigurations>>,
+
+    #[builder(field)]
+    pub optimizations: Option<SPGlobalCreateCampaignOptimizations>,
+
+    #[builder(default="SPONSORED_PRODUCTS".to_string())]
+    pub ad_product: String,
+
+    #[serde(rename = "endDateTime")]
+    pub end_datetime: Option<DateTime<Utc>>,
+
+    #[builder(default="GLOBAL".to_string())]
+    pub marketplace_scope: String,
+
+    pub marketplaces: Vec<SPGlobalMarketplace>,
+
+    pub portfolio_id: Option<String>,
+
+    #[serde(rename = "startDateTime")]
+    pub start_datetime: Option<DateTime<Utc>>,
+
+    #[builder(default=SPGlobalCreateState::Enabled)]
+    pub state: SPGlobalCreateState,
+
+    pub tags: Option<Vec<SPGlobalCreateTag>>,
+}
+
+impl<S: s_p_global_campaign_create_builder::State> SPGlobalCampaignCreateBuilder<S> {
+    /// true 为自动投放广告活动, false 为手动投放
+    pub fn auto_targeting(mut self, auto_create_targets: bool) -> Self {
+        self.auto_creation_settings = SPGlobalCreateAutoCreationSettings {
+            auto_create_targets,
+        };
+        self
+    }
+
+    /// 设置某个站点的每日预算, 多个站点多次调用; 创建时至少设置一个站点
+    pub fn daily_budget(mut self, country_code: &str, budget: f64) -> Self {
+        if self.budgets.is_empty() {
+            self.budgets.push(SPGlobalCreateBudget::daily());
+        }
+        self.budgets[0].push_marketplace(country_code, budget);
+        self
+    }
+
+    pub fn bid_strategy(mut self, bid_strategy: SPGlobalBidStrategy) -> Self {
+        self.optimizations
+            .get_or_insert_with(Default::default)
+            .bid_settings
+            .bid_strategy = Some(bid_strategy);
+        self
+    }
+
+    pub fn placement_bid_adjustment(
+        mut self,
+        placement: SPGlobalPlacement,
+        percentage: i32,
+    ) -> Self {
+        self.optimizations
+            .get_or_insert_with(Default::default)
+            .bid_settings
+            .push_placement(placement, percentage);
+        self
+    }
+
+    /// 只覆盖某个站点的竞价位置调整, 其余站点沿用全局设置
+    pub fn marketplace_placement_bid_adjustment(
+        mut self,
+        country_code: &str,
+        placement: SPGlobalPlacement,
+        percentage: i32,
+    ) -> Self {
+        marketplace_overrides(&mut self.marketplace_configurations, country_code)
+            .optimizations
+            .get_or_insert_with(Default::default)
+            .bid_settings
+            .push_placement(placement, percentage);
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateAutoCreationSettings {
+    pub auto_create_targets: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateBudget {
+    pub budget_type: SPGlobalBudgetType,
+    pub budget_value: SPGlobalCreateBudgetValue,
+    pub recurrence_time_period: SPGlobalRecurrenceTimePeriod,
+}
+
+impl SPGlobalCreateBudget {
+    pub fn daily() -> Self {
+        Self {
+            budget_type: SPGlobalBudgetType::Monetary,
+            budget_value: SPGlobalCreateBudgetValue {
+                monetary_budget_value: SPGlobalCreateMonetaryBudgetValue {
+                    marketplace_settings: vec![],
+                },
+            },
+            recurrence_time_period: SPGlobalRecurrenceTimePeriod::Daily,
+        }
+    }
+
+    pub fn push_marketplace(&mut self, country_code: &str, value: f64) {
+        self.budget_value
+            .monetary_budget_value
+            .marketplace_settings
+            .push(SPGlobalCreateMonetaryBudgetMarketplaceSetting {
+                marketplace: SPGlobalMarketplace::from_str(country_code).expect("无效的国家代码"),
+                monetary_budget: SPGlobalCreateMonetaryBudget {
+                    currency_code: SPGlobalCurrencyCode::from_country_code(country_code)
+                        .expect("无法识别的国家代码"),
+                    value,
+                },
+            });
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateBudgetValue {
+    pub monetary_budget_value: SPGlobalCreateMonetaryBudgetValue,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateMonetaryBudgetValue {
+    pub marketplace_settings: Vec<SPGlobalCreateMonetaryBudgetMarketplaceSetting>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateMonetaryBudgetMarketplaceSetting {
+    pub marketplace: SPGlobalMarketplace,
+    pub monetary_budget: SPGlobalCreateMonetaryBudget,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateMonetaryBudget {
+    pub currency_code: SPGlobalCurrencyCode,
+    pub value: f64,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateCampaignOptimizations {
+    pub bid_settings: SPGlobalCreateBidSettings,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateBidSettings {
+    pub bid_adjustments: Option<SPGlobalCreateBidAdjustments>,
+    pub bid_strategy: Option<SPGlobalBidStrategy>,
+}
+
+impl SPGlobalCreateBidSettings {
+    fn push_placement(&mut self, placement: SPGlobalPlacement, percentage: i32) {
+        self.bid_adjustments
+            .get_or_insert_with(|| SPGlobalCreateBidAdjustments {
+                placement_bid_adjustments: vec![],
+            })
+            .placement_bid_adjustments
+            .push(SPGlobalCreatePlacementBidAdjustment {
+                percentage,
+                placement,
+            });
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateBidAdjustments {
+    pub placement_bid_adjustments: Vec<SPGlobalCreatePlacementBidAdjustment>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreatePlacementBidAdjustment {
+    pub percentage: i32,
+    pub placement: SPGlobalPlacement,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateMarketplaceCampaignConfigurations {
+    pub marketplace: SPGlobalMarketplace,
+    pub overrides: SPGlobalCreateMarketplaceCampaignFieldOverrides,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCreateMarketplaceCampaignFieldOverrides {
+    pub name: Option<String>,
+    pub optimizations: Option<SPGlobalCreateCampaignOptimizations>,
+    pub state: Option<SPGlobalState>,
+    pub tags: Option<Vec<SPGlobalCreateTag>>,
+}
+
+fn marketplace_overrides<'a>(
+    configurations: &'a mut Option<Vec<SPGlobalCreateMarketplaceCampaignConfigurations>>,
+    country_code: &str,
+) -> &'a mut SPGlobalCreateMarketplaceCampaignFieldOverrides {
+    let marketplace = SPGlobalMarketplace::from_str(country_code).expect("无效的国家代码");
+    let configurations = configurations.get_or_insert_with(Vec::new);
+    let position = match configurations
+        .iter()
+        .position(|item| item.marketplace == marketplace)
+    {
+        Some(position) => position,
+        None => {
+            configurations.push(SPGlobalCreateMarketplaceCampaignConfigurations {
+                marketplace,
+                overrides: Default::default(),
+            });
+            configurations.len() - 1
+        }
+    };
+    &mut configurations[position].overrides
+}
+
+// endregion
+
+// region SPGlobalCampaignUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCampaignUpdate {
+    #[builder(start_fn)]
+    pub campaign_id: String,
+
+    #[builder(field)]
+    pub budgets: Option<Vec<SPGlobalCreateBudget>>,
+
+    #[builder(field)]
+    pub marketplace_configurations: Option<Vec<SPGlobalCreateMarketplaceCampaignConfigurations>>,
+
+    #[builder(field)]
+    pub optimizations: Option<SPGlobalCreateCampaignOptimizations>,
+
+    #[builder(field)]
+    pub state: Option<SPGlobalUpdateState>,
+
+    #[serde(rename = "endDateTime")]
+    pub end_datetime: Option<DateTime<Utc>>,
+    pub marketplaces: Option<Vec<SPGlobalMarketplace>>,
+    pub name: Option<String>,
+    pub portfolio_id: Option<String>,
+
+    #[serde(rename = "startDateTime")]
+    pub start_datetime: Option<DateTime<Utc>>,
+    pub tags: Option<Vec<SPGlobalCreateTag>>,
+}
+
+impl<S: s_p_global_campaign_update_builder::State> SPGlobalCampaignUpdateBuilder<S> {
+    pub fn state(mut self, state: &str) -> Self {
+        let state = SPGlobalUpdateState::from_str(state).unwrap();
+        self.state = Some(state);
+        self
+    }
+
+    /// 只修改某一个站点的状态, 其余站点保持全局设置
+    pub fn marketplace_state(mut self, country_code: &str, state: SPGlobalState) -> Self {
+        marketplace_overrides(&mut self.marketplace_configurations, country_code).state =
+            Some(state);
+        self
+    }
+
+    /// 设置某个站点的每日预算, 多个站点多次调用
+    pub fn daily_budget(mut self, country_code: &str, budget: f64) -> Self {
+        let budgets = self
+            .budgets
+            .get_or_insert_with(|| vec![SPGlobalCreateBudget::daily()]);
+        budgets[0].push_marketplace(country_code, budget);
+        self
+    }
+
+    pub fn bid_strategy(mut self, bid_strategy: SPGlobalBidStrategy) -> Self {
+        self.optimizations
+            .get_or_insert_with(Default::default)
+            .bid_settings
+            .bid_strategy = Some(bid_strategy);
+        self
+    }
+
+    pub fn placement_bid_adjustment(
+        mut self,
+        placement: SPGlobalPlacement,
+        percentage: i32,
+    ) -> Self {
+        self.optimizations
+            .get_or_insert_with(Default::default)
+            .bid_settings
+            .push_placement(placement, percentage);
+        self
+    }
+
+    /// 只覆盖某个站点的竞价位置调整, 其余站点沿用全局设置
+    pub fn marketplace_placement_bid_adjustment(
+        mut self,
+        country_code: &str,
+        placement: SPGlobalPlacement,
+        percentage: i32,
+    ) -> Self {
+        marketplace_overrides(&mut self.marketplace_configurations, country_code)
+            .optimizations
+            .get_or_insert_with(Default::default)
+            .bid_settings
+            .push_placement(placement, percentage);
+        self
+    }
+}
+
+// endregion
+
+// region OperationCampaignsResponse
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCampaignPartialIndex {
+    pub campaign: SPGlobalCampaign,
+    pub errors: Option<Vec<Error>>,
+    pub index: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SPGlobalCampaignMultiStatusSuccess {
+    pub campaign: SPGlobalCampaign,
+    pub index: usize,
+}
+// endregion
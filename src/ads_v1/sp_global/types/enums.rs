@@ -308,3 +308,56 @@ pub enum SPGlobalUpdateState {
     Enabled,
     Paused,
 }
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPGlobalBudgetType {
+    Monetary,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPGlobalRecurrenceTimePeriod {
+    Daily,
+    Lifetime,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPGlobalBidStrategy {
+    Manual,         // 固定竞价
+    SalesDownOnly,  // 动态竞价 - 仅降低
+    SalesUpAndDown, // 动态竞价 - 提高和降低
+    RuleBased,      // 基于规则的竞价
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPGlobalPlacement {
+    TopOfSearch,        // 搜索结果顶部(首页)
+    RestOfSearch,       // 搜索结果其余位置
+    ProductPage,        // 商品详情页
+    SiteAmazonBusiness, // Amazon Business
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPGlobalCampaignStateFilter {
+    Enabled,
+    Paused,
+    Archived,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SPGlobalAdGroupStateFilter {
+    Enabled,
+    Paused,
+    Archived,
+}
@@ -5,6 +5,7 @@ use super::enums::{
 };
 use crate::ads_v1::types::Error;
 use crate::ads_v1::types::ErrorsIndex;
+use crate::multi_status::MultiStatus;
 use bon::Builder;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -221,6 +222,25 @@ pub struct OperationGlobalTargetResponse {
     pub partial_success: Option<Vec<SPGlobalTargetPartialIndex>>,
     pub success: Option<Vec<SPGlobalTargetMultiStatusSuccess>>,
 }
+
+impl From<OperationGlobalTargetResponse> for MultiStatus<SPGlobalTarget> {
+    fn from(res: OperationGlobalTargetResponse) -> Self {
+        MultiStatus::from_parts(
+            res.success
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index, item.target)),
+            res.partial_success
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index, item.target, item.errors.unwrap_or_default())),
+            res.error
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index as usize, item.errors)),
+        )
+    }
+}
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SPGlobalTargetPartialIndex {
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use strum::{AsRefStr, Display, EnumString};
 
 #[derive(Debug, Deserialize)]
@@ -16,6 +17,15 @@ pub struct Error {
     pub message: String,
 }
 
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field_location {
+            Some(field_location) => write!(f, "{} {}: {}", self.code, field_location, self.message),
+            None => write!(f, "{}: {}", self.code, self.message),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -0,0 +1,135 @@
+use crate::client::AdsClient;
+use crate::multi_status::MultiStatus;
+use crate::operation::AdsOperation;
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use std::future::Future;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// 可按输入拆分的批量创建/更新/删除操作
+pub trait BatchOperation: AdsOperation + Sized
+where
+    Self::Response: Into<MultiStatus<Self::Item, Self::Error>>,
+{
+    type Input;
+    type Item;
+    type Error;
+
+    /// 单次请求允许的最大条数
+    const MAX_BATCH_SIZE: usize;
+
+    fn into_parts(self) -> (Arc<AdsClient>, Vec<Self::Input>);
+
+    fn from_parts(ads_client: Arc<AdsClient>, inputs: Vec<Self::Input>) -> Self;
+}
+
+/// 某一批整体请求失败(网络/限流等), range 为该批在原始输入中的位置
+#[derive(Debug)]
+pub struct ChunkError {
+    pub range: Range<usize>,
+    pub error: anyhow::Error,
+}
+
+#[derive(Debug)]
+pub struct Batched<T, E> {
+    pub status: MultiStatus<T, E>,
+    pub chunk_errors: Vec<ChunkError>,
+}
+
+impl<T, E> Batched<T, E> {
+    pub fn is_all_success(&self) -> bool {
+        self.chunk_errors.is_empty() && self.status.is_all_success()
+    }
+
+    /// 条目级错误和整批失败的输入位置
+    pub fn failed_indices(&self) -> Vec<usize> {
+        let mut indices = self.status.failed_indices();
+        for chunk_error in &self.chunk_errors {
+            indices.extend(chunk_error.range.clone());
+        }
+        indices.sort();
+        indices
+    }
+
+    pub fn failed_inputs<'a, I>(&self, inputs: &'a [I]) -> Vec<&'a I> {
+        self.failed_indices()
+            .into_iter()
+            .filter_map(|index| inputs.get(index))
+            .collect()
+    }
+}
+
+/// 按 MAX_BATCH_SIZE 拆分输入, range 为每批在原始输入中的位置
+pub fn split_batches<O>(operation: O) -> Vec<(Range<usize>, O)>
+where
+    O: BatchOperation,
+    O::Response: Into<MultiStatus<O::Item, O::Error>>,
+{
+    let (ads_client, inputs) = operation.into_parts();
+
+    let mut chunks = vec![];
+    let mut inputs = inputs.into_iter().peekable();
+    let mut offset = 0;
+    while inputs.peek().is_some() {
+        let chunk: Vec<_> = inputs.by_ref().take(O::MAX_BATCH_SIZE).collect();
+        let range = offset..offset + chunk.len();
+        offset = range.end;
+        chunks.push((range, O::from_parts(ads_client.clone(), chunk)));
+    }
+    chunks
+}
+
+/// 按 MAX_BATCH_SIZE 拆分输入, 最多 concurrency 个请求并发, 合并结果并还原 index
+pub async fn execute_batched<O>(operation: O, concurrency: usize) -> Batched<O::Item, O::Error>
+where
+    O: BatchOperation,
+    O::Response: Into<MultiStatus<O::Item, O::Error>>,
+{
+    let (ads_client, inputs) = operation.into_parts();
+    let operation = O::from_parts(ads_client.clone(), inputs);
+    execute_batched_with(operation, concurrency, move |operation| {
+        let ads_client = ads_client.clone();
+        async move { ads_client.execute(&operation).await }
+    })
+    .await
+}
+
+/// 同 execute_batched, 每批的请求由 execute 发送, 便于替换为自定义的发送方式
+pub async fn execute_batched_with<O, F, Fut>(
+    operation: O,
+    concurrency: usize,
+    execute: F,
+) -> Batched<O::Item, O::Error>
+where
+    O: BatchOperation,
+    O::Response: Into<MultiStatus<O::Item, O::Error>>,
+    F: Fn(O) -> Fut,
+    Fut: Future<Output = Result<O::Response>>,
+{
+    let chunks = split_batches(operation);
+
+    let results: Vec<(Range<usize>, Result<O::Response>)> = stream::iter(chunks)
+        .map(|(range, operation)| {
+            let res = execute(operation);
+            async move { (range, res.await) }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut batched = Batched {
+        status: MultiStatus::default(),
+        chunk_errors: vec![],
+    };
+    for (range, res) in results {
+        match res {
+            Ok(res) => batched.status.merge(res.into(), range.start),
+            Err(error) => batched.chunk_errors.push(ChunkError { range, error }),
+        }
+    }
+    batched
+        .chunk_errors
+        .sort_by_key(|chunk_error| chunk_error.range.start);
+    batched
+}
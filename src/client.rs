@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::middleware::AuthMiddleware;
+use crate::operation::{AdsOperation, OperationRequest};
 use crate::region::AmazonRegion;
 use bon::bon;
 use http::HeaderValue;
@@ -68,10 +69,21 @@ impl AdsClient {
     }
 
     #[builder]
-    pub async fn get(&self, path: &str) -> Result<Response> {
+    pub async fn get(
+        &self,
+        path: &str,
+        query: Option<&[(&str, String)]>,
+        accept: Option<&str>,
+    ) -> Result<Response> {
         let url = self.url(path);
 
-        let req_builder = self.inner.get(url);
+        let mut req_builder = self.inner.get(url);
+        if let Some(query) = query {
+            req_builder = req_builder.query(query);
+        }
+        if let Some(accept) = accept {
+            req_builder = req_builder.header(header::ACCEPT, accept);
+        }
 
         let res = req_builder.send().await?.error_for_status()?;
         Ok(res)
@@ -82,15 +94,23 @@ impl AdsClient {
         &self,
         path: &str,
         json_body: serde_json::Value,
+        query: Option<&[(&str, String)]>,
         content_type: Option<&str>,
+        accept: Option<&str>,
     ) -> Result<Response> {
         let url = self.url(path);
         let json_string = serde_json::to_string(&json_body)?;
 
         let mut req_builder = self.inner.post(url);
+        if let Some(query) = query {
+            req_builder = req_builder.query(query);
+        }
         if let Some(content_type) = content_type {
             req_builder = req_builder.header(header::CONTENT_TYPE, content_type);
         }
+        if let Some(accept) = accept {
+            req_builder = req_builder.header(header::ACCEPT, accept);
+        }
 
         let res = req_builder
             .body(json_string)
@@ -100,6 +120,77 @@ impl AdsClient {
         Ok(res)
     }
 
+    #[builder]
+    pub async fn put(
+        &self,
+        path: &str,
+        json_body: serde_json::Value,
+        content_type: Option<&str>,
+        accept: Option<&str>,
+    ) -> Result<Response> {
+        let url = self.url(path);
+        let json_string = serde_json::to_string(&json_body)?;
+
+        let mut req_builder = self.inner.put(url);
+        if let Some(content_type) = content_type {
+            req_builder = req_builder.header(header::CONTENT_TYPE, content_type);
+        }
+        if let Some(accept) = accept {
+            req_builder = req_builder.header(header::ACCEPT, accept);
+        }
+
+        let res = req_builder
+            .body(json_string)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(res)
+    }
+
+    #[builder]
+    pub async fn delete(&self, path: &str, accept: Option<&str>) -> Result<Response> {
+        let url = self.url(path);
+
+        let mut req_builder = self.inner.delete(url);
+        if let Some(accept) = accept {
+            req_builder = req_builder.header(header::ACCEPT, accept);
+        }
+
+        let res = req_builder.send().await?.error_for_status()?;
+        Ok(res)
+    }
+
+    /// 统一执行 AdsOperation 并解析响应
+    pub async fn execute<O: AdsOperation>(&self, operation: &O) -> Result<O::Response> {
+        let request = operation.to_request()?;
+        let res = self.send(&request).await?;
+        let bytes = res.bytes().await?;
+        // 部分接口成功时没有响应体, 按 null 解析, 配合 IgnoredAny 使用
+        let body: &[u8] = if bytes.is_empty() { b"null" } else { &bytes };
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    pub async fn send(&self, request: &OperationRequest) -> Result<Response> {
+        let url = self.url(&request.path);
+
+        let mut req_builder = self.inner.request(request.method.into(), url);
+        if !request.query.is_empty() {
+            req_builder = req_builder.query(&request.query);
+        }
+        if let Some(content_type) = request.content_type {
+            req_builder = req_builder.header(header::CONTENT_TYPE, content_type);
+        }
+        if let Some(accept) = request.accept {
+            req_builder = req_builder.header(header::ACCEPT, accept);
+        }
+        if let Some(body) = &request.body {
+            req_builder = req_builder.body(serde_json::to_string(body)?);
+        }
+
+        let res = req_builder.send().await?.error_for_status()?;
+        Ok(res)
+    }
+
     fn url(&self, path: &str) -> String {
         format!("{}{}", self.region.api_endpoint(), path)
     }
@@ -0,0 +1,321 @@
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::{anyhow, bail, Result};
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use strum::{AsRefStr, Display, EnumString};
+
+// region 导出类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportType {
+    Campaigns,
+    AdGroups,
+    Ads,
+    Targets,
+}
+
+impl ExportType {
+    fn path(&self) -> &'static str {
+        match self {
+            ExportType::Campaigns => "/campaigns/export",
+            ExportType::AdGroups => "/adGroups/export",
+            ExportType::Ads => "/ads/export",
+            ExportType::Targets => "/targets/export",
+        }
+    }
+
+    // 查询导出状态时 Accept 也要用对应的媒体类型
+    fn media_type(&self) -> &'static str {
+        match self {
+            ExportType::Campaigns => "application/vnd.campaignsexport.v1+json",
+            ExportType::AdGroups => "application/vnd.adgroupsexport.v1+json",
+            ExportType::Ads => "application/vnd.adsexport.v1+json",
+            ExportType::Targets => "application/vnd.targetsexport.v1+json",
+        }
+    }
+}
+
+// endregion
+
+// region 创建导出
+#[derive(Builder)]
+pub struct CreateExport {
+    ads_client: Arc<AdsClient>,
+    export_type: ExportType,
+    #[builder(default = vec![ExportAdProduct::SponsoredProducts])]
+    ad_product_filter: Vec<ExportAdProduct>,
+    // 默认 ENABLED/PAUSED/ARCHIVED 都导出
+    state_filter: Option<Vec<ExportState>>,
+}
+
+impl CreateExport {
+    pub async fn fetch(self) -> Result<ExportResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateExport {
+    type Response = ExportResponse;
+
+    fn path(&self) -> String {
+        self.export_type.path().to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(self.export_type.media_type())
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(self.export_type.media_type())
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        let mut json_body = json!({
+            "adProductFilter": self.ad_product_filter,
+        });
+        if let Some(state_filter) = &self.state_filter {
+            json_body["stateFilter"] = json!(state_filter);
+        }
+        Ok(Some(json_body))
+    }
+}
+
+// endregion
+
+// region 获取导出状态
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct GetExport {
+    ads_client: Arc<AdsClient>,
+    export_type: ExportType,
+    export_id: String,
+}
+
+impl GetExport {
+    pub async fn fetch(&self) -> Result<ExportResponse> {
+        self.ads_client.execute(self).await
+    }
+
+    /// 轮询直到完成, FAILED 或超时返回错误
+    pub async fn wait(&self, poll_interval: Duration, timeout: Duration) -> Result<ExportResponse> {
+        let started = Instant::now();
+        loop {
+            let res = self.fetch().await?;
+            match res.status {
+                ExportStatus::Completed => return Ok(res),
+                ExportStatus::Failed => {
+                    let reason = res
+                        .error
+                        .map(|error| format!("{}: {}", error.error_code, error.message))
+                        .unwrap_or_default();
+                    bail!("导出 {} 失败: {}", self.export_id, reason);
+                }
+                // 未知状态按处理中继续轮询, 直到超时
+                ExportStatus::Processing | ExportStatus::Other => {}
+            }
+            if started.elapsed() + poll_interval > timeout {
+                bail!("导出 {} 超时", self.export_id);
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// 下载 gzip 压缩的 JSON 数组, T 必须与 export_type 对应, 例如 Targets 对应 ExportedTarget
+    pub async fn fetch_document<T: ExportEntity>(&self, url: &str) -> Result<Vec<T>> {
+        if T::EXPORT_TYPE != self.export_type {
+            bail!(
+                "导出 {} 的类型是 {:?}, 不能解析为 {:?}",
+                self.export_id,
+                self.export_type,
+                T::EXPORT_TYPE
+            );
+        }
+        let res = reqwest::get(url).await?.error_for_status()?;
+        let compressed_data = res.bytes().await?;
+        let decoder = GzDecoder::new(&compressed_data[..]);
+        let items: Vec<T> = serde_json::from_reader(decoder)?;
+        Ok(items)
+    }
+
+    /// 等待完成并下载
+    pub async fn wait_and_download<T: ExportEntity>(
+        &self,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Vec<T>> {
+        let res = self.wait(poll_interval, timeout).await?;
+        let url = res
+            .url
+            .ok_or_else(|| anyhow!("导出 {} 没有下载链接", self.export_id))?;
+        self.fetch_document(&url).await
+    }
+}
+
+impl AdsOperation for GetExport {
+    type Response = ExportResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        format!("/exports/{}", self.export_id)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(self.export_type.media_type())
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportResponse {
+    pub export_id: String,
+    pub status: ExportStatus,
+    pub url: Option<String>,
+    pub url_expires_at: Option<DateTime<Utc>>,
+    pub file_size: Option<f64>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub generated_at: Option<DateTime<Utc>>,
+    pub error: Option<ExportError>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportError {
+    pub error_code: String,
+    pub message: String,
+}
+
+// endregion
+
+// region 导出的实体
+/// 导出文件中的实体, 与导出类型一一对应
+pub trait ExportEntity: DeserializeOwned {
+    const EXPORT_TYPE: ExportType;
+}
+
+impl ExportEntity for ExportedCampaign {
+    const EXPORT_TYPE: ExportType = ExportType::Campaigns;
+}
+
+impl ExportEntity for ExportedAdGroup {
+    const EXPORT_TYPE: ExportType = ExportType::AdGroups;
+}
+
+impl ExportEntity for ExportedAd {
+    const EXPORT_TYPE: ExportType = ExportType::Ads;
+}
+
+impl ExportEntity for ExportedTarget {
+    const EXPORT_TYPE: ExportType = ExportType::Targets;
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedCampaign {
+    pub campaign_id: String,
+    pub ad_product: ExportAdProduct,
+    pub portfolio_id: Option<String>,
+    pub name: String,
+    pub state: ExportState,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub targeting_settings: Option<String>,
+    pub budget: Option<Value>,
+    pub bidding: Option<Value>,
+    pub delivery_status: Option<String>,
+    pub creation_date_time: Option<DateTime<Utc>>,
+    pub last_updated_date_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedAdGroup {
+    pub ad_group_id: String,
+    pub campaign_id: String,
+    pub ad_product: ExportAdProduct,
+    pub name: String,
+    pub state: ExportState,
+    pub default_bid: Option<f64>,
+    pub delivery_status: Option<String>,
+    pub creation_date_time: Option<DateTime<Utc>>,
+    pub last_updated_date_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedAd {
+    pub ad_id: String,
+    pub ad_group_id: String,
+    pub campaign_id: String,
+    pub ad_product: ExportAdProduct,
+    pub state: ExportState,
+    pub creative: Option<Value>,
+    pub delivery_status: Option<String>,
+    pub creation_date_time: Option<DateTime<Utc>>,
+    pub last_updated_date_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedTarget {
+    pub target_id: String,
+    pub ad_group_id: Option<String>,
+    pub campaign_id: String,
+    pub ad_product: ExportAdProduct,
+    pub state: ExportState,
+    // KEYWORD / PRODUCT / PRODUCT_CATEGORY / AUTO 等
+    pub target_type: Option<String>,
+    pub negative: Option<bool>,
+    pub bid: Option<f64>,
+    pub target_details: Option<Value>,
+    pub delivery_status: Option<String>,
+    pub creation_date_time: Option<DateTime<Utc>>,
+    pub last_updated_date_time: Option<DateTime<Utc>>,
+}
+
+// endregion
+
+// region 枚举
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Display, AsRefStr, EnumString,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExportAdProduct {
+    SponsoredProducts,
+    SponsoredBrands,
+    SponsoredDisplay,
+}
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Display, AsRefStr, EnumString,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExportState {
+    Enabled,
+    Paused,
+    Archived,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExportStatus {
+    Processing,
+    Completed,
+    Failed,
+    #[serde(other)]
+    Other,
+}
+
+// endregion
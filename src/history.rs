@@ -0,0 +1,280 @@
+use crate::client::AdsClient;
+use crate::operation::AdsOperation;
+use crate::pagination::{collect_all, paginate, Collected};
+use anyhow::Result;
+use bon::{bon, Builder};
+use chrono::{DateTime, TimeZone, Utc};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use strum::{AsRefStr, Display, EnumString};
+
+// region ListHistory
+const MAX_HISTORY_COUNT: i32 = 200;
+
+#[derive(Builder)]
+pub struct ListHistory {
+    #[builder(field)]
+    event_types: BTreeMap<HistoryEntityType, HistoryEventTypeFilter>,
+    ads_client: Arc<AdsClient>,
+    from_date: DateTime<Utc>,
+    to_date: DateTime<Utc>,
+    // 每页条数, 最大 200, 超出时按 200 请求
+    #[builder(default = MAX_HISTORY_COUNT)]
+    count: i32,
+    #[builder(default = HistorySortDirection::Desc)]
+    sort_direction: HistorySortDirection,
+    // stream/collect_all 合计最多返回的条数, 不设置时遍历所有页
+    limit: Option<usize>,
+}
+
+impl<S: list_history_builder::State> ListHistoryBuilder<S> {
+    /// 查询某类实体的变更, ids 为空表示该类的全部实体, change_types 为空表示全部变更类型
+    pub fn entity(
+        mut self,
+        entity_type: HistoryEntityType,
+        ids: Vec<&str>,
+        change_types: Vec<HistoryChangeType>,
+    ) -> Self {
+        let filter = self.event_types.entry(entity_type).or_default();
+        if !ids.is_empty() {
+            filter
+                .event_type_ids
+                .get_or_insert_with(Vec::new)
+                .extend(ids.into_iter().map(|item| item.to_string()));
+        }
+        if !change_types.is_empty() {
+            filter
+                .filters
+                .get_or_insert_with(Vec::new)
+                .extend(change_types);
+        }
+        self
+    }
+}
+
+#[bon]
+impl ListHistory {
+    #[builder]
+    pub async fn fetch(&self, #[builder(default = 0)] page_offset: i32) -> Result<HistoryResponse> {
+        let operation = self.with_body_fields(vec![("pageOffset", json!(page_offset))]);
+        self.ads_client.execute(&operation).await
+    }
+
+    /// 按 pageOffset 逐页请求直到 maxPageNumber, 用于审计全部变更
+    pub fn stream(&self) -> impl Stream<Item = Result<HistoryEvent>> + '_ {
+        paginate(self.limit, move |page_offset| async move {
+            let page_offset = page_offset.map_or(Ok(0), |offset| offset.parse::<i32>())?;
+            let res = self.fetch().page_offset(page_offset).call().await?;
+            let next_page_offset = res
+                .next_page_offset(page_offset, self.page_size())
+                .map(|offset| offset.to_string());
+            Ok((res.events, next_page_offset))
+        })
+    }
+
+    pub async fn collect_all(&self) -> Collected<HistoryEvent> {
+        collect_all(self.stream()).await
+    }
+
+    fn page_size(&self) -> i32 {
+        self.count.clamp(1, MAX_HISTORY_COUNT)
+    }
+}
+
+impl AdsOperation for ListHistory {
+    type Response = HistoryResponse;
+
+    fn path(&self) -> String {
+        "/history".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/json")
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "fromDate": self.from_date.timestamp_millis(),
+            "toDate": self.to_date.timestamp_millis(),
+            "eventTypes": self.event_types,
+            "count": self.page_size(),
+            "sort": {
+                "key": "DATE",
+                "direction": self.sort_direction,
+            },
+        })))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEventTypeFilter {
+    pub event_type_ids: Option<Vec<String>>,
+    pub filters: Option<Vec<HistoryChangeType>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryResponse {
+    #[serde(default)]
+    pub events: Vec<HistoryEvent>,
+    pub max_page_number: Option<i32>,
+    pub total_records: Option<i64>,
+}
+
+impl HistoryResponse {
+    /// 下一页的 pageOffset, 本页不满或超过 maxPageNumber 时返回 None
+    pub fn next_page_offset(&self, page_offset: i32, count: i32) -> Option<i32> {
+        let next = page_offset + 1;
+        let has_more = !self.events.is_empty()
+            && self.events.len() >= count as usize
+            && self
+                .max_page_number
+                .is_none_or(|max_page_number| next <= max_page_number);
+        has_more.then_some(next)
+    }
+}
+
+// endregion
+
+// region HistoryEvent
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEvent {
+    pub change_type: HistoryChangeType,
+    pub entity_id: String,
+    pub entity_type: HistoryEntityType,
+    pub metadata: Option<Value>,
+    pub previous_value: Option<String>,
+    pub new_value: Option<String>,
+    // 毫秒时间戳
+    pub timestamp: i64,
+}
+
+impl HistoryEvent {
+    pub fn changed_at(&self) -> Option<DateTime<Utc>> {
+        Utc.timestamp_millis_opt(self.timestamp).single()
+    }
+
+    pub fn before(&self) -> HistoryValue {
+        HistoryValue::parse(&self.change_type, self.previous_value.as_deref())
+    }
+
+    pub fn after(&self) -> HistoryValue {
+        HistoryValue::parse(&self.change_type, self.new_value.as_deref())
+    }
+}
+
+/// 按变更类型解析的前后值
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistoryValue {
+    // 预算/竞价金额
+    Amount(f64),
+    // 位置竞价调整百分比
+    Percentage(f64),
+    State(String),
+    Text(String),
+    Empty,
+}
+
+impl HistoryValue {
+    fn parse(change_type: &HistoryChangeType, value: Option<&str>) -> Self {
+        let Some(value) = value.map(str::trim).filter(|value| !value.is_empty()) else {
+            return HistoryValue::Empty;
+        };
+        let number = value.trim_end_matches('%').parse::<f64>().ok();
+        match (change_type, number) {
+            (
+                HistoryChangeType::BudgetAmount
+                | HistoryChangeType::BidAmount
+                | HistoryChangeType::DefaultBid,
+                Some(number),
+            ) => HistoryValue::Amount(number),
+            (HistoryChangeType::PlacementGroup, Some(number)) => HistoryValue::Percentage(number),
+            (HistoryChangeType::Status, _) => HistoryValue::State(value.to_uppercase()),
+            _ => HistoryValue::Text(value.to_string()),
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            HistoryValue::Amount(value) | HistoryValue::Percentage(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+// endregion
+
+// region 枚举
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Display,
+    AsRefStr,
+    EnumString,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum HistoryEntityType {
+    Campaign,
+    AdGroup,
+    Ad,
+    Keyword,
+    NegativeKeyword,
+    ProductTargeting,
+    NegativeProductTargeting,
+    Theme,
+    // 响应中出现的其它实体类型, 例如组合/预算规则, 不要用于查询
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Display, AsRefStr, EnumString)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum HistoryChangeType {
+    // 预算
+    BudgetAmount,
+    BudgetType,
+    InBudget,
+    // 竞价
+    BidAmount,
+    DefaultBid,
+    SmartBiddingStrategy,
+    // 状态
+    Status,
+    // 位置竞价调整
+    PlacementGroup,
+    Name,
+    StartDate,
+    EndDate,
+    Created,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HistorySortDirection {
+    Asc,
+    Desc,
+}
+
+// endregion
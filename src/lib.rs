@@ -1,10 +1,19 @@
 pub mod auth;
+pub mod batch;
 pub mod region;
 pub mod middleware;
+pub mod multi_status;
 pub mod client;
 pub mod util;
+pub mod operation;
+pub mod pagination;
 pub mod v3;
 pub mod ads_v1;
+pub mod exports;
+pub mod history;
 pub mod products;
 pub mod reporting;
-pub mod reporting_type;
\ No newline at end of file
+pub mod reporting_type;
+pub mod sb;
+pub mod sd;
+pub mod stream;
@@ -0,0 +1,159 @@
+use anyhow::{anyhow, Result};
+use std::fmt::Display;
+
+use crate::ads_v1::types::Error;
+
+/// 批量创建/更新/删除的 207 响应, 每个条目通过 index 对应提交时的输入
+#[derive(Debug)]
+pub struct MultiStatus<T, E = Error> {
+    pub entries: Vec<MultiStatusEntry<T, E>>,
+}
+
+#[derive(Debug)]
+pub struct MultiStatusEntry<T, E = Error> {
+    pub index: usize,
+    pub status: EntryStatus<T, E>,
+}
+
+#[derive(Debug)]
+pub enum EntryStatus<T, E = Error> {
+    Success(T),
+    // 部分站点成功, 例如全球广告
+    PartialSuccess { value: T, errors: Vec<E> },
+    Error(Vec<E>),
+}
+
+impl<T, E> EntryStatus<T, E> {
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            EntryStatus::Success(value) | EntryStatus::PartialSuccess { value, .. } => Some(value),
+            EntryStatus::Error(_) => None,
+        }
+    }
+
+    pub fn errors(&self) -> &[E] {
+        match self {
+            EntryStatus::Success(_) => &[],
+            EntryStatus::PartialSuccess { errors, .. } | EntryStatus::Error(errors) => errors,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, EntryStatus::Success(_))
+    }
+}
+
+impl<T, E> Default for MultiStatus<T, E> {
+    fn default() -> Self {
+        Self { entries: vec![] }
+    }
+}
+
+impl<T, E> MultiStatus<T, E> {
+    pub fn from_parts(
+        success: impl IntoIterator<Item = (usize, T)>,
+        partial_success: impl IntoIterator<Item = (usize, T, Vec<E>)>,
+        error: impl IntoIterator<Item = (usize, Vec<E>)>,
+    ) -> Self {
+        let mut entries: Vec<_> = success
+            .into_iter()
+            .map(|(index, value)| MultiStatusEntry {
+                index,
+                status: EntryStatus::Success(value),
+            })
+            .chain(
+                partial_success
+                    .into_iter()
+                    .map(|(index, value, errors)| MultiStatusEntry {
+                        index,
+                        status: EntryStatus::PartialSuccess { value, errors },
+                    }),
+            )
+            .chain(error.into_iter().map(|(index, errors)| MultiStatusEntry {
+                index,
+                status: EntryStatus::Error(errors),
+            }))
+            .collect();
+        entries.sort_by_key(|entry| entry.index);
+        Self { entries }
+    }
+
+    /// 合并分批提交的结果, offset 为该批第一个输入在原始列表中的位置
+    pub fn merge(&mut self, other: MultiStatus<T, E>, offset: usize) {
+        self.entries
+            .extend(other.entries.into_iter().map(|entry| MultiStatusEntry {
+                index: entry.index + offset,
+                status: entry.status,
+            }));
+        self.entries.sort_by_key(|entry| entry.index);
+    }
+
+    pub fn is_all_success(&self) -> bool {
+        self.entries.iter().all(|entry| entry.status.is_success())
+    }
+
+    pub fn successes(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.status.value().map(|value| (entry.index, value)))
+    }
+
+    /// 有错误的条目, 包括部分成功
+    pub fn failures(&self) -> impl Iterator<Item = (usize, &[E])> {
+        self.entries
+            .iter()
+            .filter(|entry| !entry.status.is_success())
+            .map(|entry| (entry.index, entry.status.errors()))
+    }
+
+    pub fn failed_indices(&self) -> Vec<usize> {
+        self.failures().map(|(index, _)| index).collect()
+    }
+
+    /// 取出失败条目对应的输入, 用于修正后重新提交
+    pub fn failed_inputs<'a, I>(&self, inputs: &'a [I]) -> Vec<&'a I> {
+        self.failures()
+            .filter_map(|(index, _)| inputs.get(index))
+            .collect()
+    }
+
+    /// 按 index 把结果与输入一一对应
+    pub fn with_inputs<'a, I>(
+        &'a self,
+        inputs: &'a [I],
+    ) -> impl Iterator<Item = (&'a I, &'a EntryStatus<T, E>)> {
+        self.entries
+            .iter()
+            .filter_map(|entry| inputs.get(entry.index).map(|input| (input, &entry.status)))
+    }
+}
+
+impl<T, E: Display> MultiStatus<T, E> {
+    /// 全部成功时按 index 顺序返回结果, 否则返回汇总的错误信息
+    pub fn into_result(self) -> Result<Vec<T>> {
+        if self.is_all_success() {
+            return Ok(self
+                .entries
+                .into_iter()
+                .filter_map(|entry| match entry.status {
+                    EntryStatus::Success(value) => Some(value),
+                    _ => None,
+                })
+                .collect());
+        }
+        let total = self.entries.len();
+        let messages: Vec<String> = self
+            .failures()
+            .map(|(index, errors)| {
+                let errors: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+                format!("[{}] {}", index, errors.join("; "))
+            })
+            .collect();
+        Err(anyhow!(
+            "{}/{} 条失败: {}",
+            messages.len(),
+            total,
+            messages.join(", ")
+        ))
+    }
+}
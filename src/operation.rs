@@ -0,0 +1,152 @@
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl From<HttpMethod> for http::Method {
+    fn from(method: HttpMethod) -> Self {
+        match method {
+            HttpMethod::Get => http::Method::GET,
+            HttpMethod::Post => http::Method::POST,
+            HttpMethod::Put => http::Method::PUT,
+            HttpMethod::Delete => http::Method::DELETE,
+        }
+    }
+}
+
+/// 描述一次接口调用: 方法/路径/媒体类型/请求体/响应类型
+/// 由 AdsClient::execute 统一发送, dry-run、批量、重试、录制等只需在执行器处实现一次
+pub trait AdsOperation {
+    type Response: DeserializeOwned;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Post
+    }
+
+    fn path(&self) -> String;
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(None)
+    }
+
+    /// 在请求体中附加 nextToken, 用于按 nextToken 翻页的列表接口
+    fn with_next_token(&self, next_token: Option<&str>) -> WithParams<'_, Self>
+    where
+        Self: Sized,
+    {
+        let fields = next_token
+            .map(|token| vec![("nextToken", Value::from(token))])
+            .unwrap_or_default();
+        self.with_body_fields(fields)
+    }
+
+    /// 在请求体中附加分页等调用时才确定的字段
+    fn with_body_fields(&self, body_fields: Vec<(&'static str, Value)>) -> WithParams<'_, Self>
+    where
+        Self: Sized,
+    {
+        WithParams {
+            operation: self,
+            body_fields,
+            query: vec![],
+        }
+    }
+
+    /// 在查询参数中附加分页等调用时才确定的参数
+    fn with_query(&self, query: Vec<(&'static str, String)>) -> WithParams<'_, Self>
+    where
+        Self: Sized,
+    {
+        WithParams {
+            operation: self,
+            body_fields: vec![],
+            query,
+        }
+    }
+
+    /// 不发送, 只生成请求描述
+    fn to_request(&self) -> Result<OperationRequest> {
+        Ok(OperationRequest {
+            method: self.method(),
+            path: self.path(),
+            query: self.query(),
+            content_type: self.content_type(),
+            accept: self.accept(),
+            body: self.body()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OperationRequest {
+    pub method: HttpMethod,
+    pub path: String,
+    pub query: Vec<(&'static str, String)>,
+    pub content_type: Option<&'static str>,
+    pub accept: Option<&'static str>,
+    pub body: Option<Value>,
+}
+
+/// 包装一个操作, 追加请求体字段或查询参数, 其余部分保持不变
+pub struct WithParams<'a, O> {
+    operation: &'a O,
+    body_fields: Vec<(&'static str, Value)>,
+    query: Vec<(&'static str, String)>,
+}
+
+impl<O: AdsOperation> AdsOperation for WithParams<'_, O> {
+    type Response = O::Response;
+
+    fn method(&self) -> HttpMethod {
+        self.operation.method()
+    }
+
+    fn path(&self) -> String {
+        self.operation.path()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = self.operation.query();
+        query.extend(self.query.iter().cloned());
+        query
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        self.operation.content_type()
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        self.operation.accept()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        let body = self.operation.body()?;
+        if self.body_fields.is_empty() {
+            return Ok(body);
+        }
+        let mut body = body.unwrap_or_else(|| Value::Object(Default::default()));
+        for (key, value) in &self.body_fields {
+            body[*key] = value.clone();
+        }
+        Ok(Some(body))
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::future::Future;
+
+/// 按 nextToken 自动翻页, fetch_page 接收上一页返回的 next_token, 返回 (本页数据, next_token)
+///
+/// 每页大小沿用各接口 filter 中的 max_results, limit 是所有页合计的最大条数,
+/// 达到 limit 后不再请求下一页
+pub fn paginate<'a, T, F, Fut>(
+    limit: Option<usize>,
+    fetch_page: F,
+) -> impl Stream<Item = Result<T>> + 'a
+where
+    T: 'a,
+    F: FnMut(Option<String>) -> Fut + 'a,
+    Fut: Future<Output = Result<(Vec<T>, Option<String>)>> + 'a,
+{
+    stream::try_unfold(
+        (Some(None::<String>), fetch_page),
+        |(state, mut fetch_page)| async move {
+            let Some(next_token) = state else {
+                return Ok::<_, anyhow::Error>(None);
+            };
+            let (items, next_token) = fetch_page(next_token).await?;
+            let next = match next_token {
+                Some(token) if !token.is_empty() && !items.is_empty() => Some(Some(token)),
+                _ => None,
+            };
+            let items = stream::iter(items.into_iter().map(Ok));
+            Ok(Some((items, (next, fetch_page))))
+        },
+    )
+    .try_flatten()
+    .take(limit.unwrap_or(usize::MAX))
+}
+
+/// 收集分页结果, 出错时停止, 已拿到的数据保留在 items 中
+#[derive(Debug)]
+pub struct Collected<T> {
+    pub items: Vec<T>,
+    pub error: Option<anyhow::Error>,
+}
+
+impl<T> Collected<T> {
+    pub fn is_complete(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// 有错误时丢弃已收集的数据
+    pub fn into_result(self) -> Result<Vec<T>> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.items),
+        }
+    }
+}
+
+pub async fn collect_all<T>(stream: impl Stream<Item = Result<T>>) -> Collected<T> {
+    let mut stream = std::pin::pin!(stream);
+    let mut items = vec![];
+    while let Some(item) = stream.next().await {
+        match item {
+            Ok(item) => items.push(item),
+            Err(error) => {
+                return Collected {
+                    items,
+                    error: Some(error),
+                }
+            }
+        }
+    }
+    Collected { items, error: None }
+}
@@ -1,11 +1,17 @@
 use crate::client::AdsClient;
+use crate::operation::AdsOperation;
 use anyhow::Result;
-use bon::{bon, builder, Builder};
+use bon::{bon, Builder};
+use futures::stream::{self, Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use serde_with::skip_serializing_none;
 use std::sync::Arc;
 use strum::AsRefStr;
 
+// 单页最多 300 个商品, 查询的 asins/skus 也按这个数量分批
+pub const PRODUCT_METADATA_MAX_PAGE_SIZE: usize = 300;
+
 #[derive(Builder)]
 pub struct ProductMetadata {
     ads_client: Arc<AdsClient>,
@@ -16,27 +22,153 @@ pub struct ProductMetadata {
 impl ProductMetadata {
     #[builder]
     pub async fn fetch(
-        self,
+        &self,
         #[builder(default = 1)] page_index: i32,
         #[builder(default = 300)] page_size: i32,
+        cursor_token: Option<&str>,
     ) -> Result<ProductMetadataResponse> {
-        let mut filter = serde_json::to_value(&self.filter)?;
-        filter["pageIndex"] = page_index.into();
-        filter["pageSize"] = page_size.into();
+        let mut fields = vec![
+            ("pageIndex", json!(page_index)),
+            ("pageSize", json!(page_size)),
+        ];
+        if let Some(cursor_token) = cursor_token {
+            fields.push(("cursorToken", json!(cursor_token)));
+        }
+        self.ads_client
+            .execute(&self.with_body_fields(fields))
+            .await
+    }
+
+    /// 遍历所有页, 有 cursor_token 时按游标翻页, 否则按 page_index 翻页
+    ///
+    /// page_size 会被限制在 1..=300 之间
+    pub fn stream(&self, page_size: i32) -> impl Stream<Item = Result<ProductMetadataModel>> + '_ {
+        let page_size = page_size.clamp(1, PRODUCT_METADATA_MAX_PAGE_SIZE as i32);
+        stream::try_unfold(Some((1, None::<String>)), move |state| async move {
+            let Some((page_index, cursor_token)) = state else {
+                return Ok::<_, anyhow::Error>(None);
+            };
+            let res = self
+                .fetch()
+                .page_index(page_index)
+                .page_size(page_size)
+                .maybe_cursor_token(cursor_token.as_deref())
+                .call()
+                .await?;
+            let count = res.product_metadata_list.len();
+            let next = match res.cursor_token {
+                _ if count == 0 => None,
+                // 接口重复返回同一个游标时停止, 避免一直请求同一页
+                Some(token) if cursor_token.as_deref() == Some(token.as_str()) => None,
+                Some(token) if !token.is_empty() => Some((page_index + 1, Some(token))),
+                _ if count >= page_size as usize => Some((page_index + 1, None)),
+                _ => None,
+            };
+            let items = stream::iter(res.product_metadata_list.into_iter().map(Ok));
+            Ok(Some((items, next)))
+        })
+        .try_flatten()
+    }
+
+    pub async fn collect_all(&self, page_size: i32) -> Result<Vec<ProductMetadataModel>> {
+        self.stream(page_size).try_collect().await
+    }
+}
+
+impl AdsOperation for ProductMetadata {
+    type Response = ProductMetadataResponse;
+
+    fn path(&self) -> String {
+        "/product/metadata".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
+    }
+}
+
+// region ProductMetadataLookup
+/// 按 ASIN/SKU 查询商品信息, 数量不限, 内部按接口上限分批请求
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ProductMetadataLookup {
+    ads_client: Arc<AdsClient>,
+
+    #[builder(default="SP".to_string())]
+    ad_type: String,
+
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    asins: Option<Vec<String>>,
+
+    // 只支持卖家SP
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    skus: Option<Vec<String>>,
+
+    #[builder(default = false)]
+    check_eligibility: bool,
 
-        let res = self
-            .ads_client
-            .post()
-            .path("/product/metadata")
-            .json_body(filter)
-            .call()
-            .await?;
+    #[builder(default = true)]
+    check_item_details: bool,
+
+    locale: Option<String>,
+
+    #[builder(default = PRODUCT_METADATA_MAX_PAGE_SIZE)]
+    chunk_size: usize,
+}
+
+impl ProductMetadataLookup {
+    pub async fn fetch(self) -> Result<Vec<ProductMetadataModel>> {
+        let chunk_size = self.chunk_size.clamp(1, PRODUCT_METADATA_MAX_PAGE_SIZE);
+        let mut result = vec![];
+        if let Some(asins) = &self.asins {
+            for chunk in asins.chunks(chunk_size) {
+                let filter = self.filter(Some(chunk.to_vec()), None);
+                result.extend(self.fetch_chunk(filter, chunk_size).await?);
+            }
+        }
+        if let Some(skus) = &self.skus {
+            for chunk in skus.chunks(chunk_size) {
+                let filter = self.filter(None, Some(chunk.to_vec()));
+                result.extend(self.fetch_chunk(filter, chunk_size).await?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn filter(
+        &self,
+        asins: Option<Vec<String>>,
+        skus: Option<Vec<String>>,
+    ) -> ProductMetadataFilter {
+        ProductMetadataFilter {
+            ad_type: self.ad_type.clone(),
+            asins,
+            check_eligibility: self.check_eligibility,
+            check_item_details: self.check_item_details,
+            is_global_store_selection: false,
+            locale: self.locale.clone(),
+            search_str: None,
+            skus,
+            sort_by: "SUGGESTED".to_string(),
+            sort_order: "DESC".to_string(),
+        }
+    }
 
-        let data = res.json::<ProductMetadataResponse>().await?;
-        Ok(data)
+    async fn fetch_chunk(
+        &self,
+        filter: ProductMetadataFilter,
+        chunk_size: usize,
+    ) -> Result<Vec<ProductMetadataModel>> {
+        let api = ProductMetadata::builder()
+            .ads_client(self.ads_client.clone())
+            .filter(filter)
+            .build();
+        api.collect_all(chunk_size as i32).await
     }
 }
 
+// endregion
+
 #[skip_serializing_none]
 #[derive(Serialize, Debug, Builder)]
 #[serde(rename_all = "camelCase")]
@@ -173,3 +305,187 @@ pub enum EligibilityStatus {
     Eligible,   // 符合条件
     Ineligible, // 不符合
 }
+
+// endregion
+
+// endregion
+
+// region ProductEligibility
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ProductEligibility {
+    #[builder(field)]
+    products: Vec<ProductDetails>,
+    ads_client: Arc<AdsClient>,
+    // sp sb sd
+    #[builder(default="sp".to_string())]
+    ad_type: String,
+    // 语言, 默认是市场语言
+    locale: Option<String>,
+}
+
+impl<S: product_eligibility_builder::State> ProductEligibilityBuilder<S> {
+    pub fn asin(mut self, asin: &str) -> Self {
+        self.products.push(ProductDetails {
+            asin: Some(asin.to_string()),
+            sku: None,
+        });
+        self
+    }
+
+    // 只支持卖家
+    pub fn sku(mut self, sku: &str) -> Self {
+        self.products.push(ProductDetails {
+            asin: None,
+            sku: Some(sku.to_string()),
+        });
+        self
+    }
+
+    pub fn asins(self, asins: Vec<&str>) -> Self {
+        asins.into_iter().fold(self, |builder, asin| builder.asin(asin))
+    }
+}
+
+impl ProductEligibility {
+    pub async fn fetch(self) -> Result<ProductEligibilityResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for ProductEligibility {
+    type Response = ProductEligibilityResponse;
+
+    fn path(&self) -> String {
+        "/eligibility/product/list".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/json")
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        let mut json_body = json!({
+            "adType": self.ad_type,
+            "productDetailsList": self.products,
+        });
+        if let Some(locale) = &self.locale {
+            json_body["locale"] = json!(locale);
+        }
+        Ok(Some(json_body))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductDetails {
+    pub asin: Option<String>,
+    pub sku: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductEligibilityResponse {
+    #[serde(default)]
+    pub product_response_list: Vec<ProductEligibilityItem>,
+}
+
+impl ProductEligibilityResponse {
+    /// 可投放广告的商品, 用于创建广告前过滤
+    pub fn eligible(&self) -> Vec<&ProductDetails> {
+        self.product_response_list
+            .iter()
+            .filter(|item| item.is_eligible())
+            .map(|item| &item.product_details)
+            .collect()
+    }
+
+    pub fn ineligible(&self) -> Vec<&ProductEligibilityItem> {
+        self.product_response_list
+            .iter()
+            .filter(|item| !item.is_eligible())
+            .collect()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductEligibilityItem {
+    pub product_details: ProductDetails,
+    #[serde(default)]
+    pub eligibility_status_list: Vec<ProductEligibilityStatus>,
+    pub overall_status: Option<ProductOverallStatus>,
+}
+
+impl ProductEligibilityItem {
+    pub fn is_eligible(&self) -> bool {
+        match &self.overall_status {
+            Some(status) => status.is_eligible,
+            None => !self
+                .eligibility_status_list
+                .iter()
+                .any(|item| item.severity == Some(IneligibilitySeverity::Error)),
+        }
+    }
+
+    pub fn reason_codes(&self) -> Vec<&IneligibilityReasonCode> {
+        self.eligibility_status_list
+            .iter()
+            .map(|item| &item.reason_code)
+            .collect()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductOverallStatus {
+    pub is_eligible: bool,
+    #[serde(default)]
+    pub is_eligible_with_warning: bool,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductEligibilityStatus {
+    pub reason_code: IneligibilityReasonCode,
+    pub severity: Option<IneligibilitySeverity>,
+    pub message: Option<String>,
+    pub help_url: Option<String>,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, AsRefStr, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum IneligibilitySeverity {
+    Error,   // 不能投放
+    Warning, // 可以投放但有风险
+}
+
+// region 不符合资格的原因
+#[derive(Debug, Serialize, Deserialize, AsRefStr, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum IneligibilityReasonCode {
+    ItemNotFound,              // 商品不存在
+    NotBuyable,                // 不可购买
+    NotInBuybox,               // 没有购物车
+    OutOfStock,                // 缺货
+    AdultProduct,              // 成人商品
+    ClosedCategory,            // 受限类目
+    IneligibleCondition,       // 二手/翻新等不支持的商品状况
+    IneligibleProductCategory, // 类目不支持该广告类型
+    IneligibleBrand,           // 未完成品牌注册
+    ListingSuppressed,         // listing 被屏蔽
+    ParentAsin,                // 父体不能投放
+    #[serde(other)]
+    Other,
+}
+// endregion
+
+// endregion
@@ -2,15 +2,19 @@ use super::reporting_type::{
     Column, CreateReportStatus, SpCampaignsColumns, SpTargetingColumn, TimeUnit,
 };
 use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
 use bon::Builder;
 use chrono::{DateTime, NaiveDate, Utc};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
 use flate2::read::GzDecoder;
+use reqwest::StatusCode;
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 // region 创建报告
 #[derive(Builder)]
@@ -21,18 +25,23 @@ pub struct CreateReport {
 
 impl CreateReport {
     pub async fn fetch(self) -> Result<ReportResponse> {
-        let filter = serde_json::to_value(&self.filter)?;
-
-        let res = self
-            .ads_client
-            .post()
-            .path("/reporting/reports")
-            .json_body(filter)
-            .content_type("application/vnd.createasyncreportrequest.v3+json")
-            .call()
-            .await?;
-        let res_data = res.json::<ReportResponse>().await?;
-        Ok(res_data)
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateReport {
+    type Response = ReportResponse;
+
+    fn path(&self) -> String {
+        "/reporting/reports".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/vnd.createasyncreportrequest.v3+json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
     }
 }
 
@@ -158,20 +167,154 @@ pub struct GetReport {
 
 impl GetReport {
     pub async fn fetch(&self) -> Result<ReportResponse> {
-        let res = self
-            .ads_client
-            .get()
-            .path(&format!("/reporting/reports/{}", self.report_id))
-            .call()
-            .await?;
-        Ok(res.json::<ReportResponse>().await?)
+        self.ads_client.execute(self).await
     }
 
+    /// 链接过期时返回 403 错误
     pub async fn fetch_document(&self, url: &str) -> Result<Value> {
-        let res = reqwest::get(url).await?;
+        let res = reqwest::get(url).await?.error_for_status()?;
         let compressed_data = res.bytes().await?;
         let decoder = GzDecoder::new(&compressed_data[..]);
         let json_value: Value = serde_json::from_reader(decoder)?;
         Ok(json_value)
     }
 }
+
+impl AdsOperation for GetReport {
+    type Response = ReportResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        format!("/reporting/reports/{}", self.report_id)
+    }
+}
+
+// region 报告任务
+/// 创建报告 -> 轮询状态 -> 下载文档的完整流程
+///
+/// 新任务传入 `filter`; 进程重启后传入已保存的 `report_id` 即可继续轮询
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ReportJob {
+    ads_client: Arc<AdsClient>,
+    filter: Option<CreateReportFilter>,
+    report_id: Option<String>,
+    #[builder(default = Duration::from_secs(5))]
+    poll_interval: Duration, // 首次轮询间隔
+    #[builder(default = Duration::from_secs(60))]
+    max_poll_interval: Duration, // 退避后的最大轮询间隔
+    #[builder(default = Duration::from_secs(3600))]
+    timeout: Duration,
+}
+
+impl ReportJob {
+    /// 链接过期前预留的余量, 避免下载途中过期
+    const URL_EXPIRY_MARGIN: i64 = 60;
+
+    pub fn report_id(&self) -> Option<&str> {
+        self.report_id.as_deref()
+    }
+
+    /// 创建报告并返回 report_id, 已有 report_id 时直接返回
+    pub async fn start(&mut self) -> Result<String> {
+        if let Some(report_id) = &self.report_id {
+            return Ok(report_id.clone());
+        }
+        let filter = self
+            .filter
+            .take()
+            .ok_or_else(|| anyhow!("ReportJob 需要 filter 或 report_id"))?;
+        let res = CreateReport::builder()
+            .ads_client(self.ads_client.clone())
+            .filter(filter)
+            .build()
+            .fetch()
+            .await?;
+        self.report_id = Some(res.report_id.clone());
+        Ok(res.report_id)
+    }
+
+    fn get_report(&self, report_id: &str) -> GetReport {
+        GetReport::builder()
+            .ads_client(self.ads_client.clone())
+            .report_id(report_id)
+            .build()
+    }
+
+    /// 轮询直到完成, 间隔按 2 倍退避到 max_poll_interval; FAILED 或超时返回错误
+    /// 限流(429)和服务端错误(5xx)按同样的间隔重试
+    pub async fn wait(&mut self) -> Result<ReportResponse> {
+        let report_id = self.start().await?;
+        let api = self.get_report(&report_id);
+        let started = Instant::now();
+        let mut interval = self.poll_interval;
+        loop {
+            let state = match api.fetch().await {
+                Ok(res) => match res.status {
+                    CreateReportStatus::Completed => return Ok(res),
+                    CreateReportStatus::Failed => {
+                        bail!(
+                            "报告 {} 失败: {}",
+                            report_id,
+                            res.failure_reason.unwrap_or_default()
+                        );
+                    }
+                    status => format!("{:?}", status),
+                },
+                Err(err) if is_transient(&err) => err.to_string(),
+                Err(err) => return Err(err),
+            };
+            if started.elapsed() + interval > self.timeout {
+                bail!("报告 {} 超时, 当前状态 {}", report_id, state);
+            }
+            tokio::time::sleep(interval).await;
+            interval = (interval * 2).min(self.max_poll_interval);
+        }
+    }
+
+    /// 等待完成并下载, 链接缺失或已过期时重新获取
+    /// 下载时链接过期(403)会重新获取链接并重试一次
+    pub async fn run(&mut self) -> Result<Value> {
+        let mut res = self.wait().await?;
+        let expiry_margin = chrono::Duration::seconds(Self::URL_EXPIRY_MARGIN);
+        let expired = res
+            .url_expires_at
+            .is_some_and(|expires_at| expires_at <= Utc::now() + expiry_margin);
+        if res.url.is_none() || expired {
+            res = self.get_report(&res.report_id).fetch().await?;
+        }
+        let api = self.get_report(&res.report_id);
+        let url = res
+            .url
+            .ok_or_else(|| anyhow!("报告 {} 没有下载链接", res.report_id))?;
+        match api.fetch_document(&url).await {
+            Err(err) if error_status(&err) == Some(StatusCode::FORBIDDEN) => {
+                let res = api.fetch().await?;
+                let url = res
+                    .url
+                    .ok_or_else(|| anyhow!("报告 {} 没有下载链接", res.report_id))?;
+                api.fetch_document(&url).await
+            }
+            result => result,
+        }
+    }
+
+    /// 下载并反序列化为具体的行类型
+    pub async fn run_as<T: DeserializeOwned>(&mut self) -> Result<Vec<T>> {
+        let document = self.run().await?;
+        Ok(serde_json::from_value(document)?)
+    }
+}
+
+fn error_status(err: &anyhow::Error) -> Option<StatusCode> {
+    err.downcast_ref::<reqwest::Error>()?.status()
+}
+
+// 限流或服务端错误, 稍后重试可能成功
+fn is_transient(err: &anyhow::Error) -> bool {
+    error_status(err)
+        .is_some_and(|status| status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error())
+}
@@ -0,0 +1,7 @@
+pub mod ad_groups;
+pub mod ads;
+pub mod campaigns;
+pub mod keywords;
+pub mod negative_keywords;
+pub mod targets;
+pub mod types;
\ No newline at end of file
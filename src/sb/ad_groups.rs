@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use crate::util::wrap_include_optional;
+
+use anyhow::Result;
+use bon::{bon, Builder};
+
+use super::campaigns::SBNameFilter;
+use super::types::ad_groups::{
+    SBAdGroup, SBAdGroupCreate, SBAdGroupUpdate, SBOperationAdGroupsResponse,
+};
+use super::types::enums::SBState;
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+
+const SB_AD_GROUP_MEDIA_TYPE: &str = "application/vnd.sbadgroupresource.v4+json";
+
+// region ListAdGroups
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListAdGroups {
+    ads_client: Arc<AdsClient>,
+    filter: ListAdGroupsFilter,
+}
+
+#[bon]
+impl ListAdGroups {
+    #[builder]
+    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListAdGroupsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+}
+
+impl AdsOperation for ListAdGroups {
+    type Response = ListAdGroupsResponse;
+
+    fn path(&self) -> String {
+        "/sb/v4/adGroups/list".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_AD_GROUP_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_AD_GROUP_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Builder, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAdGroupsFilter {
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_group_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub campaign_id_filter: Option<Vec<String>>,
+
+    pub include_extended_data_fields: Option<bool>,
+
+    #[builder(default = 100)]
+    pub max_results: i32,
+
+    pub name_filter: Option<SBNameFilter>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    pub state_filter: Option<Vec<SBState>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAdGroupsResponse {
+    pub ad_groups: Option<Vec<SBAdGroup>>,
+    pub next_token: Option<String>,
+    pub total_results: Option<usize>,
+}
+
+// endregion
+
+// region CreateAdGroups
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct CreateAdGroups {
+    ads_client: Arc<AdsClient>,
+    ad_groups: Vec<SBAdGroupCreate>,
+}
+
+impl CreateAdGroups {
+    pub async fn fetch(self) -> Result<SBOperationAdGroupsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateAdGroups {
+    type Response = SBOperationAdGroupsResponse;
+
+    fn path(&self) -> String {
+        "/sb/v4/adGroups".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_AD_GROUP_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_AD_GROUP_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "adGroups": self.ad_groups
+        })))
+    }
+}
+
+// endregion
+
+// region UpdateAdGroups
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct UpdateAdGroups {
+    ads_client: Arc<AdsClient>,
+    ad_groups: Vec<SBAdGroupUpdate>,
+}
+
+impl UpdateAdGroups {
+    pub async fn fetch(self) -> Result<SBOperationAdGroupsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateAdGroups {
+    type Response = SBOperationAdGroupsResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sb/v4/adGroups".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_AD_GROUP_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_AD_GROUP_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "adGroups": self.ad_groups
+        })))
+    }
+}
+
+// endregion
+
+// region DelAdGroups
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct DelAdGroups {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    ad_group_ids: Vec<String>,
+}
+
+impl DelAdGroups {
+    pub async fn fetch(self) -> Result<SBOperationAdGroupsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for DelAdGroups {
+    type Response = SBOperationAdGroupsResponse;
+
+    fn path(&self) -> String {
+        "/sb/v4/adGroups/delete".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_AD_GROUP_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_AD_GROUP_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "adGroupIdFilter": { "include": self.ad_group_ids }
+        })))
+    }
+}
+
+// endregion
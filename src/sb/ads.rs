@@ -0,0 +1,290 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use crate::util::wrap_include_optional;
+
+use anyhow::Result;
+use bon::{bon, Builder};
+
+use super::campaigns::SBNameFilter;
+use super::types::ads::{
+    SBAd, SBAdUpdate, SBOperationAdsResponse, SBProductCollectionAdCreate,
+    SBStoreSpotlightAdCreate, SBVideoAdCreate,
+};
+use super::types::enums::SBState;
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+
+const SB_AD_MEDIA_TYPE: &str = "application/vnd.sbadresource.v4+json";
+
+// region ListAds
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListAds {
+    ads_client: Arc<AdsClient>,
+    filter: ListAdsFilter,
+}
+
+#[bon]
+impl ListAds {
+    #[builder]
+    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListAdsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+}
+
+impl AdsOperation for ListAds {
+    type Response = ListAdsResponse;
+
+    fn path(&self) -> String {
+        "/sb/v4/ads/list".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Builder, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAdsFilter {
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_group_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub campaign_id_filter: Option<Vec<String>>,
+
+    pub include_extended_data_fields: Option<bool>,
+
+    #[builder(default = 100)]
+    pub max_results: i32,
+
+    pub name_filter: Option<SBNameFilter>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    pub state_filter: Option<Vec<SBState>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAdsResponse {
+    pub ads: Option<Vec<SBAd>>,
+    pub next_token: Option<String>,
+    pub total_results: Option<usize>,
+}
+
+// endregion
+
+// region CreateProductCollectionAds
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct CreateProductCollectionAds {
+    ads_client: Arc<AdsClient>,
+    ads: Vec<SBProductCollectionAdCreate>,
+}
+
+impl CreateProductCollectionAds {
+    pub async fn fetch(self) -> Result<SBOperationAdsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateProductCollectionAds {
+    type Response = SBOperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/sb/v4/ads/productCollection".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "ads": self.ads
+        })))
+    }
+}
+
+// endregion
+
+// region CreateStoreSpotlightAds
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct CreateStoreSpotlightAds {
+    ads_client: Arc<AdsClient>,
+    ads: Vec<SBStoreSpotlightAdCreate>,
+}
+
+impl CreateStoreSpotlightAds {
+    pub async fn fetch(self) -> Result<SBOperationAdsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateStoreSpotlightAds {
+    type Response = SBOperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/sb/v4/ads/storeSpotlight".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "ads": self.ads
+        })))
+    }
+}
+
+// endregion
+
+// region CreateVideoAds
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct CreateVideoAds {
+    ads_client: Arc<AdsClient>,
+    ads: Vec<SBVideoAdCreate>,
+}
+
+impl CreateVideoAds {
+    pub async fn fetch(self) -> Result<SBOperationAdsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateVideoAds {
+    type Response = SBOperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/sb/v4/ads/video".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "ads": self.ads
+        })))
+    }
+}
+
+// endregion
+
+// region UpdateAds
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct UpdateAds {
+    ads_client: Arc<AdsClient>,
+    ads: Vec<SBAdUpdate>,
+}
+
+impl UpdateAds {
+    pub async fn fetch(self) -> Result<SBOperationAdsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateAds {
+    type Response = SBOperationAdsResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sb/v4/ads".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "ads": self.ads
+        })))
+    }
+}
+
+// endregion
+
+// region DelAds
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct DelAds {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    ad_ids: Vec<String>,
+}
+
+impl DelAds {
+    pub async fn fetch(self) -> Result<SBOperationAdsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for DelAds {
+    type Response = SBOperationAdsResponse;
+
+    fn path(&self) -> String {
+        "/sb/v4/ads/delete".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_AD_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "adIdFilter": { "include": self.ad_ids }
+        })))
+    }
+}
+
+// endregion
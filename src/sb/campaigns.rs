@@ -0,0 +1,217 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use crate::util::wrap_include_optional;
+
+use anyhow::Result;
+use bon::{bon, Builder};
+
+use super::types::campaigns::{
+    SBCampaign, SBCampaignCreate, SBCampaignUpdate, SBOperationCampaignsResponse,
+};
+use super::types::enums::{SBQueryTermMatchType, SBState};
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+
+const SB_CAMPAIGN_MEDIA_TYPE: &str = "application/vnd.sbcampaignresource.v4+json";
+
+// region ListCampaigns
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListCampaigns {
+    ads_client: Arc<AdsClient>,
+    filter: ListCampaignsFilter,
+}
+
+#[bon]
+impl ListCampaigns {
+    #[builder]
+    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListCampaignsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+}
+
+impl AdsOperation for ListCampaigns {
+    type Response = ListCampaignsResponse;
+
+    fn path(&self) -> String {
+        "/sb/v4/campaigns/list".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_CAMPAIGN_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_CAMPAIGN_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Builder, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListCampaignsFilter {
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub campaign_id_filter: Option<Vec<String>>,
+
+    pub include_extended_data_fields: Option<bool>,
+
+    #[builder(default = 100)]
+    pub max_results: i32,
+
+    pub name_filter: Option<SBNameFilter>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub portfolio_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    pub state_filter: Option<Vec<SBState>>,
+}
+
+#[derive(Serialize, Builder, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SBNameFilter {
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub include: Vec<String>,
+    #[builder(default=SBQueryTermMatchType::BroadMatch)]
+    pub query_term_match_type: SBQueryTermMatchType,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListCampaignsResponse {
+    pub campaigns: Option<Vec<SBCampaign>>,
+    pub next_token: Option<String>,
+    pub total_results: Option<usize>,
+}
+
+// endregion
+
+// region CreateCampaigns
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct CreateCampaigns {
+    ads_client: Arc<AdsClient>,
+    campaigns: Vec<SBCampaignCreate>,
+}
+
+impl CreateCampaigns {
+    pub async fn fetch(self) -> Result<SBOperationCampaignsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateCampaigns {
+    type Response = SBOperationCampaignsResponse;
+
+    fn path(&self) -> String {
+        "/sb/v4/campaigns".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_CAMPAIGN_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_CAMPAIGN_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "campaigns": self.campaigns
+        })))
+    }
+}
+
+// endregion
+
+// region UpdateCampaigns
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct UpdateCampaigns {
+    ads_client: Arc<AdsClient>,
+    campaigns: Vec<SBCampaignUpdate>,
+}
+
+impl UpdateCampaigns {
+    pub async fn fetch(self) -> Result<SBOperationCampaignsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateCampaigns {
+    type Response = SBOperationCampaignsResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sb/v4/campaigns".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_CAMPAIGN_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_CAMPAIGN_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "campaigns": self.campaigns
+        })))
+    }
+}
+
+// endregion
+
+// region DelCampaigns
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct DelCampaigns {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|item:Vec<&str>| item.into_iter().map(|item| item.to_string()).collect::<Vec<String>>())]
+    campaign_ids: Vec<String>,
+}
+
+impl DelCampaigns {
+    pub async fn fetch(self) -> Result<SBOperationCampaignsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for DelCampaigns {
+    type Response = SBOperationCampaignsResponse;
+
+    fn path(&self) -> String {
+        "/sb/v4/campaigns/delete".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(SB_CAMPAIGN_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(SB_CAMPAIGN_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "campaignIdFilter": { "include": self.campaign_ids }
+        })))
+    }
+}
+
+// endregion
@@ -0,0 +1,158 @@
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::Builder;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use super::types::enums::{SBKeywordMatchType, SBKeywordState};
+use super::types::keywords::{SBKeyword, SBKeywordCreate, SBKeywordResponse, SBKeywordUpdate};
+
+// region ListKeywords
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListKeywords {
+    ads_client: Arc<AdsClient>,
+    filter: ListKeywordsFilter,
+}
+
+impl ListKeywords {
+    pub async fn fetch(self) -> Result<Vec<SBKeyword>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for ListKeywords {
+    type Response = Vec<SBKeyword>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/sb/keywords".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        self.filter.to_query()
+    }
+}
+
+// SB 关键词列表接口是 GET 请求, 过滤条件通过 query 参数传递, 多个值用逗号分隔
+#[derive(Builder, Debug)]
+#[builder(on(String, into))]
+pub struct ListKeywordsFilter {
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_group_id_filter: Option<Vec<String>>,
+
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub campaign_id_filter: Option<Vec<String>>,
+
+    // 最大 5000
+    pub count: Option<i32>,
+
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub keyword_id_filter: Option<Vec<String>>,
+
+    pub keyword_text: Option<String>,
+    pub locale: Option<String>,
+    pub match_type_filter: Option<Vec<SBKeywordMatchType>>,
+
+    #[builder(default = 0)]
+    pub start_index: i32,
+    pub state_filter: Option<Vec<SBKeywordState>>,
+}
+
+impl ListKeywordsFilter {
+    fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("startIndex", self.start_index.to_string())];
+        if let Some(count) = self.count {
+            query.push(("count", count.to_string()));
+        }
+        if let Some(ref ids) = self.ad_group_id_filter {
+            query.push(("adGroupIdFilter", ids.join(",")));
+        }
+        if let Some(ref ids) = self.campaign_id_filter {
+            query.push(("campaignIdFilter", ids.join(",")));
+        }
+        if let Some(ref ids) = self.keyword_id_filter {
+            query.push(("keywordIdFilter", ids.join(",")));
+        }
+        if let Some(ref keyword_text) = self.keyword_text {
+            query.push(("keywordText", keyword_text.clone()));
+        }
+        if let Some(ref locale) = self.locale {
+            query.push(("locale", locale.clone()));
+        }
+        if let Some(ref match_types) = self.match_type_filter {
+            let match_types: Vec<String> =
+                match_types.iter().map(|item| item.to_string()).collect();
+            query.push(("matchTypeFilter", match_types.join(",")));
+        }
+        if let Some(ref states) = self.state_filter {
+            let states: Vec<String> = states.iter().map(|item| item.to_string()).collect();
+            query.push(("stateFilter", states.join(",")));
+        }
+        query
+    }
+}
+
+// endregion
+
+// region CreateKeywords
+#[derive(Builder)]
+pub struct CreateKeywords {
+    ads_client: Arc<AdsClient>,
+    keywords: Vec<SBKeywordCreate>,
+}
+
+impl CreateKeywords {
+    pub async fn fetch(self) -> Result<Vec<SBKeywordResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateKeywords {
+    type Response = Vec<SBKeywordResponse>;
+
+    fn path(&self) -> String {
+        "/sb/keywords".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.keywords)))
+    }
+}
+
+// endregion
+
+// region UpdateKeywords
+#[derive(Builder)]
+pub struct UpdateKeywords {
+    ads_client: Arc<AdsClient>,
+    keywords: Vec<SBKeywordUpdate>,
+}
+
+impl UpdateKeywords {
+    pub async fn fetch(self) -> Result<Vec<SBKeywordResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateKeywords {
+    type Response = Vec<SBKeywordResponse>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sb/keywords".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.keywords)))
+    }
+}
+
+// endregion
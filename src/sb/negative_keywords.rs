@@ -0,0 +1,116 @@
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::Builder;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use super::types::keywords::{
+    SBKeywordResponse, SBNegativeKeyword, SBNegativeKeywordCreate, SBNegativeKeywordUpdate,
+};
+
+// region ListNegativeKeywords
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListNegativeKeywords {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    campaign_id_filter: Option<Vec<String>>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    ad_group_id_filter: Option<Vec<String>>,
+    #[builder(default = 0)]
+    start_index: i32,
+    count: Option<i32>,
+}
+
+impl ListNegativeKeywords {
+    pub async fn fetch(self) -> Result<Vec<SBNegativeKeyword>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for ListNegativeKeywords {
+    type Response = Vec<SBNegativeKeyword>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/sb/negativeKeywords".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("startIndex", self.start_index.to_string())];
+        if let Some(count) = self.count {
+            query.push(("count", count.to_string()));
+        }
+        if let Some(ref ids) = self.campaign_id_filter {
+            query.push(("campaignIdFilter", ids.join(",")));
+        }
+        if let Some(ref ids) = self.ad_group_id_filter {
+            query.push(("adGroupIdFilter", ids.join(",")));
+        }
+        query
+    }
+}
+
+// endregion
+
+// region CreateNegativeKeywords
+#[derive(Builder)]
+pub struct CreateNegativeKeywords {
+    ads_client: Arc<AdsClient>,
+    keywords: Vec<SBNegativeKeywordCreate>,
+}
+
+impl CreateNegativeKeywords {
+    pub async fn fetch(self) -> Result<Vec<SBKeywordResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateNegativeKeywords {
+    type Response = Vec<SBKeywordResponse>;
+
+    fn path(&self) -> String {
+        "/sb/negativeKeywords".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.keywords)))
+    }
+}
+
+// endregion
+
+// region UpdateNegativeKeywords
+#[derive(Builder)]
+pub struct UpdateNegativeKeywords {
+    ads_client: Arc<AdsClient>,
+    keywords: Vec<SBNegativeKeywordUpdate>,
+}
+
+impl UpdateNegativeKeywords {
+    pub async fn fetch(self) -> Result<Vec<SBKeywordResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateNegativeKeywords {
+    type Response = Vec<SBKeywordResponse>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sb/negativeKeywords".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.keywords)))
+    }
+}
+
+// endregion
@@ -0,0 +1,122 @@
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::{bon, Builder};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use super::types::enums::SBTargetFilterType;
+use super::types::targets::{
+    ListSBTargetsResponse, SBCreateTargetsResponse, SBTargetCreate, SBTargetFilter, SBTargetUpdate,
+    SBUpdateTargetsResponse,
+};
+
+// region ListTargets
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListTargets {
+    #[builder(field)]
+    filters: Vec<SBTargetFilter>,
+    ads_client: Arc<AdsClient>,
+    #[builder(default = 100)]
+    max_results: i32,
+}
+
+impl<S: list_targets_builder::State> ListTargetsBuilder<S> {
+    pub fn filter(mut self, filter_type: SBTargetFilterType, values: Vec<&str>) -> Self {
+        self.filters.push(SBTargetFilter {
+            filter_type,
+            values: values.into_iter().map(|item| item.to_string()).collect(),
+        });
+        self
+    }
+}
+
+#[bon]
+impl ListTargets {
+    #[builder]
+    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListSBTargetsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+}
+
+impl AdsOperation for ListTargets {
+    type Response = ListSBTargetsResponse;
+
+    fn path(&self) -> String {
+        "/sb/targets/list".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "filters": self.filters,
+            "maxResults": self.max_results,
+        })))
+    }
+}
+
+// endregion
+
+// region CreateTargets
+#[derive(Builder)]
+pub struct CreateTargets {
+    ads_client: Arc<AdsClient>,
+    targets: Vec<SBTargetCreate>,
+}
+
+impl CreateTargets {
+    pub async fn fetch(self) -> Result<SBCreateTargetsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateTargets {
+    type Response = SBCreateTargetsResponse;
+
+    fn path(&self) -> String {
+        "/sb/targets".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "targets": self.targets
+        })))
+    }
+}
+
+// endregion
+
+// region UpdateTargets
+#[derive(Builder)]
+pub struct UpdateTargets {
+    ads_client: Arc<AdsClient>,
+    targets: Vec<SBTargetUpdate>,
+}
+
+impl UpdateTargets {
+    pub async fn fetch(self) -> Result<SBUpdateTargetsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateTargets {
+    type Response = SBUpdateTargetsResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sb/targets".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "targets": self.targets
+        })))
+    }
+}
+
+// endregion
@@ -0,0 +1,7 @@
+pub mod ad_groups;
+pub mod ads;
+pub mod campaigns;
+pub mod enums;
+pub mod errors;
+pub mod keywords;
+pub mod targets;
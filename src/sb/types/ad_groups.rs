@@ -0,0 +1,77 @@
+use super::campaigns::SBServingStatusDetail;
+use super::enums::{SBCreateState, SBServingStatus, SBState};
+use super::errors::SBMutationError;
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+// region SBAdGroup
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdGroup {
+    pub ad_group_id: String,
+    pub campaign_id: String,
+    pub extended_data: Option<SBAdGroupExtendedData>,
+    pub name: String,
+    pub state: SBState,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdGroupExtendedData {
+    pub creation_date: Option<DateTime<Utc>>,
+    pub last_update_date: Option<DateTime<Utc>>,
+    pub serving_status: Option<SBServingStatus>,
+    pub serving_status_details: Option<Vec<SBServingStatusDetail>>,
+}
+// endregion
+
+// region SBAdGroupCreate
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdGroupCreate {
+    #[builder(start_fn)]
+    pub campaign_id: String,
+    pub name: String,
+    #[builder(default=SBCreateState::Enabled)]
+    pub state: SBCreateState,
+}
+// endregion
+
+// region SBAdGroupUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdGroupUpdate {
+    #[builder(start_fn)]
+    pub ad_group_id: String,
+    pub name: Option<String>,
+    pub state: Option<SBCreateState>,
+}
+// endregion
+
+// region OperationAdGroupsResponse
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBOperationAdGroupsResponse {
+    pub ad_groups: SBAdGroupsMultiStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdGroupsMultiStatus {
+    pub error: Option<Vec<SBMutationError>>,
+    pub success: Option<Vec<SBAdGroupMutationSuccess>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdGroupMutationSuccess {
+    pub ad_group: Option<SBAdGroup>,
+    pub ad_group_id: Option<String>,
+    pub index: usize,
+}
+// endregion
@@ -0,0 +1,242 @@
+use super::campaigns::SBServingStatusDetail;
+use super::enums::{SBCreateState, SBCreativeType, SBLandingPageType, SBServingStatus, SBState};
+use super::errors::SBMutationError;
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+// region SBAd
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAd {
+    pub ad_group_id: String,
+    pub ad_id: String,
+    pub campaign_id: String,
+    pub creative: Option<SBCreative>,
+    pub extended_data: Option<SBAdExtendedData>,
+    pub landing_page: Option<SBLandingPage>,
+    pub name: String,
+    pub state: SBState,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBCreative {
+    pub asins: Option<Vec<String>>,
+    #[serde(rename = "brandLogoAssetID")]
+    pub brand_logo_asset_id: Option<String>,
+    pub brand_logo_crop: Option<SBAssetCrop>,
+    pub brand_name: Option<String>,
+    #[serde(rename = "customImageAssetId")]
+    pub custom_image_asset_id: Option<String>,
+    pub headline: Option<String>,
+    pub subpages: Option<Vec<SBSubpage>>,
+    #[serde(rename = "type")]
+    pub creative_type: Option<SBCreativeType>,
+    pub video_asset_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdExtendedData {
+    pub creation_date: Option<DateTime<Utc>>,
+    pub last_update_date: Option<DateTime<Utc>>,
+    pub serving_status: Option<SBServingStatus>,
+    pub serving_status_details: Option<Vec<SBServingStatusDetail>>,
+}
+
+// endregion
+
+// region SBLandingPage 落地页
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBLandingPage {
+    pub asins: Option<Vec<String>>,
+    pub page_type: SBLandingPageType,
+    pub url: Option<String>,
+}
+
+impl SBLandingPage {
+    /// 亚马逊根据传入的 ASIN 自动生成的商品列表页
+    pub fn product_list(asins: Vec<&str>) -> Self {
+        Self {
+            asins: Some(asins.into_iter().map(|asin| asin.to_string()).collect()),
+            page_type: SBLandingPageType::ProductList,
+            url: None,
+        }
+    }
+
+    /// 品牌旗舰店页面, 也可以是旗舰店的子页面
+    pub fn store(url: &str) -> Self {
+        Self {
+            asins: None,
+            page_type: SBLandingPageType::Store,
+            url: Some(url.to_string()),
+        }
+    }
+
+    /// 自定义链接, 需要是亚马逊站内的链接
+    pub fn custom_url(url: &str) -> Self {
+        Self {
+            asins: None,
+            page_type: SBLandingPageType::CustomUrl,
+            url: Some(url.to_string()),
+        }
+    }
+
+    /// 商品详情页, 仅视频广告可用
+    pub fn detail_page(asin: &str) -> Self {
+        Self {
+            asins: Some(vec![asin.to_string()]),
+            page_type: SBLandingPageType::DetailPage,
+            url: None,
+        }
+    }
+}
+
+// endregion
+
+// region 素材
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAssetCrop {
+    pub height: i32,
+    pub left: i32,
+    pub top: i32,
+    pub width: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBSubpage {
+    pub asin: String,
+    pub page_title: String,
+    pub url: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBProductCollectionCreative {
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    pub asins: Vec<String>,
+    #[serde(rename = "brandLogoAssetID")]
+    pub brand_logo_asset_id: String,
+    pub brand_logo_crop: Option<SBAssetCrop>,
+    pub brand_name: String,
+    pub custom_image_asset_id: Option<String>,
+    pub headline: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBStoreSpotlightCreative {
+    #[serde(rename = "brandLogoAssetID")]
+    pub brand_logo_asset_id: String,
+    pub brand_logo_crop: Option<SBAssetCrop>,
+    pub brand_name: String,
+    pub headline: String,
+    // 至少三个子页面
+    pub subpages: Vec<SBSubpage>,
+}
+
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SBVideoCreative {
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    pub asins: Vec<String>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    pub video_asset_ids: Vec<String>,
+}
+
+// endregion
+
+// region 创建广告
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBProductCollectionAdCreate {
+    #[builder(start_fn)]
+    pub ad_group_id: String,
+    pub creative: SBProductCollectionCreative,
+    pub landing_page: SBLandingPage,
+    pub name: String,
+    #[builder(default=SBCreateState::Enabled)]
+    pub state: SBCreateState,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBStoreSpotlightAdCreate {
+    #[builder(start_fn)]
+    pub ad_group_id: String,
+    pub creative: SBStoreSpotlightCreative,
+    pub landing_page: SBLandingPage,
+    pub name: String,
+    #[builder(default=SBCreateState::Enabled)]
+    pub state: SBCreateState,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBVideoAdCreate {
+    #[builder(start_fn)]
+    pub ad_group_id: String,
+    pub creative: SBVideoCreative,
+    pub landing_page: Option<SBLandingPage>,
+    pub name: String,
+    #[builder(default=SBCreateState::Enabled)]
+    pub state: SBCreateState,
+}
+
+// endregion
+
+// region SBAdUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdUpdate {
+    #[builder(start_fn)]
+    pub ad_id: String,
+    pub name: Option<String>,
+    pub state: Option<SBCreateState>,
+}
+// endregion
+
+// region OperationAdsResponse
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBOperationAdsResponse {
+    pub ads: SBAdsMultiStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdsMultiStatus {
+    pub error: Option<Vec<SBMutationError>>,
+    pub success: Option<Vec<SBAdMutationSuccess>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBAdMutationSuccess {
+    pub ad: Option<SBAd>,
+    pub ad_id: Option<String>,
+    pub index: usize,
+}
+// endregion
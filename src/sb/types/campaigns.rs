@@ -0,0 +1,231 @@
+use super::enums::{
+    SBBidAdjustmentPlacement, SBBidOptimizationStrategy, SBBudgetType, SBCostType, SBCreateState,
+    SBGoal, SBProductLocation, SBServingStatus, SBShopperSegment, SBState,
+};
+use super::errors::SBMutationError;
+use bon::Builder;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+
+// region SBCampaign
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBCampaign {
+    pub bidding: Option<SBBidding>,
+    pub brand_entity_id: Option<String>,
+    pub budget: f64,
+    pub budget_type: SBBudgetType,
+    pub campaign_id: String,
+    pub cost_type: Option<SBCostType>,
+    pub end_date: Option<NaiveDate>,
+    pub extended_data: Option<SBCampaignExtendedData>,
+    pub goal: Option<SBGoal>,
+    pub is_multi_ad_groups_enabled: Option<bool>,
+    pub name: String,
+    pub portfolio_id: Option<String>,
+    pub product_location: Option<SBProductLocation>,
+    pub start_date: Option<NaiveDate>,
+    pub state: SBState,
+    pub tags: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBCampaignExtendedData {
+    pub creation_date: Option<DateTime<Utc>>,
+    pub last_update_date: Option<DateTime<Utc>>,
+    pub serving_status: Option<SBServingStatus>,
+    pub serving_status_details: Option<Vec<SBServingStatusDetail>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBServingStatusDetail {
+    pub help_url: Option<String>,
+    pub message: Option<String>,
+    pub name: Option<String>,
+}
+
+// endregion
+
+// region SBBidding 竞价优化
+
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBBidding {
+    pub bid_adjustments_by_placement: Option<Vec<SBBidAdjustmentByPlacement>>,
+    pub bid_adjustments_by_shopper_segment: Option<Vec<SBBidAdjustmentByShopperSegment>>,
+    // true 时由亚马逊自动调整竞价, 此时不能设置广告位竞价调整
+    pub bid_optimization: Option<bool>,
+    pub bid_optimization_strategy: Option<SBBidOptimizationStrategy>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBBidAdjustmentByPlacement {
+    pub percentage: i32,
+    pub placement: SBBidAdjustmentPlacement,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBBidAdjustmentByShopperSegment {
+    pub percentage: i32,
+    pub shopper_segment: SBShopperSegment,
+}
+
+// endregion
+
+// region SBCampaignCreate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBCampaignCreate {
+    #[builder(start_fn)]
+    pub name: String,
+
+    #[builder(field)]
+    pub bidding: Option<SBBidding>,
+
+    pub brand_entity_id: Option<String>,
+    pub budget: f64,
+
+    #[builder(default=SBBudgetType::Daily)]
+    pub budget_type: SBBudgetType,
+    pub cost_type: Option<SBCostType>,
+    pub end_date: Option<NaiveDate>,
+
+    #[builder(default=SBGoal::PageVisit)]
+    pub goal: SBGoal,
+    pub portfolio_id: Option<String>,
+    pub product_location: Option<SBProductLocation>,
+    pub start_date: Option<NaiveDate>,
+
+    #[builder(default=SBCreateState::Enabled)]
+    pub state: SBCreateState,
+    pub tags: Option<HashMap<String, String>>,
+}
+
+impl<S: s_b_campaign_create_builder::State> SBCampaignCreateBuilder<S> {
+    /// 开启自动竞价, 开启后亚马逊会按策略调整竞价
+    pub fn bid_optimization(mut self, strategy: SBBidOptimizationStrategy) -> Self {
+        self.bidding
+            .get_or_insert_with(Default::default)
+            .enable_optimization(strategy);
+        self
+    }
+
+    /// 手动设置广告位竞价调整, 会关闭自动竞价
+    pub fn placement_bid_adjustment(
+        mut self,
+        placement: SBBidAdjustmentPlacement,
+        percentage: i32,
+    ) -> Self {
+        self.bidding
+            .get_or_insert_with(Default::default)
+            .push_placement(placement, percentage);
+        self
+    }
+
+    pub fn new_to_brand_bid_adjustment(mut self, percentage: i32) -> Self {
+        self.bidding
+            .get_or_insert_with(Default::default)
+            .bid_adjustments_by_shopper_segment = Some(vec![SBBidAdjustmentByShopperSegment {
+            percentage,
+            shopper_segment: SBShopperSegment::NewToBrandPurchase,
+        }]);
+        self
+    }
+}
+
+impl SBBidding {
+    // 自动竞价和广告位调整互斥, 同时出现时接口会拒绝
+    fn enable_optimization(&mut self, strategy: SBBidOptimizationStrategy) {
+        self.bid_optimization = Some(true);
+        self.bid_optimization_strategy = Some(strategy);
+        self.bid_adjustments_by_placement = None;
+    }
+
+    fn push_placement(&mut self, placement: SBBidAdjustmentPlacement, percentage: i32) {
+        self.bid_optimization = Some(false);
+        self.bid_optimization_strategy = None;
+        self.bid_adjustments_by_placement
+            .get_or_insert_with(Vec::new)
+            .push(SBBidAdjustmentByPlacement {
+                percentage,
+                placement,
+            });
+    }
+}
+
+// endregion
+
+// region SBCampaignUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBCampaignUpdate {
+    #[builder(start_fn)]
+    pub campaign_id: String,
+
+    #[builder(field)]
+    pub bidding: Option<SBBidding>,
+
+    pub budget: Option<f64>,
+    pub end_date: Option<NaiveDate>,
+    pub name: Option<String>,
+    pub portfolio_id: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub state: Option<SBCreateState>,
+    pub tags: Option<HashMap<String, String>>,
+}
+
+impl<S: s_b_campaign_update_builder::State> SBCampaignUpdateBuilder<S> {
+    pub fn bid_optimization(mut self, strategy: SBBidOptimizationStrategy) -> Self {
+        self.bidding
+            .get_or_insert_with(Default::default)
+            .enable_optimization(strategy);
+        self
+    }
+
+    pub fn placement_bid_adjustment(
+        mut self,
+        placement: SBBidAdjustmentPlacement,
+        percentage: i32,
+    ) -> Self {
+        self.bidding
+            .get_or_insert_with(Default::default)
+            .push_placement(placement, percentage);
+        self
+    }
+}
+
+// endregion
+
+// region OperationCampaignsResponse
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBOperationCampaignsResponse {
+    pub campaigns: SBCampaignsMultiStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBCampaignsMultiStatus {
+    pub error: Option<Vec<SBMutationError>>,
+    pub success: Option<Vec<SBCampaignMutationSuccess>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBCampaignMutationSuccess {
+    pub campaign: Option<SBCampaign>,
+    pub campaign_id: Option<String>,
+    pub index: usize,
+}
+// endregion
@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumString};
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBState {
+    Enabled,
+    Paused,
+    Archived,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBCreateState {
+    Enabled,
+    Paused,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBBudgetType {
+    Daily,
+    Lifetime,
+}
+
+// 广告活动目标
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBGoal {
+    PageVisit,            // 提升页面访问
+    BrandImpressionShare, // 提升品牌曝光份额
+}
+
+// 商品销售渠道
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBProductLocation {
+    SoldOnAmazon,
+    NotSoldOnAmazon,
+    SoldOnDtc,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBCostType {
+    Cpc,
+    Vcpm,
+}
+
+// 自动竞价优化策略
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBBidOptimizationStrategy {
+    MaximizeImmediateSales,      // 最大化即时销售
+    MaximizeNewToBrandCustomers, // 最大化品牌新客
+}
+
+// 竞价调整的广告位
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBBidAdjustmentPlacement {
+    Home,       // 首页
+    DetailPage, // 商品详情页
+    Other,      // 其他位置
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBShopperSegment {
+    NewToBrandPurchase,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBQueryTermMatchType {
+    BroadMatch,
+    ExactMatch,
+}
+
+// 落地页类型
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBLandingPageType {
+    ProductList, // 新的商品列表页
+    Store,       // 品牌旗舰店页面
+    CustomUrl,   // 自定义链接
+    DetailPage,  // 商品详情页
+}
+
+// 广告素材类型
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBCreativeType {
+    ProductCollection,
+    StoreSpotlight,
+    Video,
+    BrandVideo,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBServingStatus {
+    Ready,
+    Running,
+    Ended,
+    Paused,
+    Archived,
+    Pending,
+    PendingReview,
+    Rejected,
+    Scheduled,
+    OutOfBudget,
+    PortfolioOutOfBudget,
+    AdvertiserPaymentFailure,
+    #[serde(other)]
+    Other,
+}
+
+// region 关键词 (SB 关键词接口仍然是小写风格)
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+#[serde(rename_all = "camelCase")]
+pub enum SBKeywordMatchType {
+    Broad,
+    Exact,
+    Phrase,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+#[serde(rename_all = "camelCase")]
+pub enum SBNegativeKeywordMatchType {
+    NegativeExact,
+    NegativePhrase,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+#[serde(rename_all = "camelCase")]
+pub enum SBKeywordState {
+    Enabled,
+    Paused,
+    Pending,
+    Archived,
+    Draft,
+}
+
+// endregion
+
+// region 商品投放
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+#[serde(rename_all = "camelCase")]
+pub enum SBTargetingExpressionType {
+    AsinCategorySameAs,
+    AsinBrandSameAs,
+    AsinPriceLessThan,
+    AsinPriceBetween,
+    AsinPriceGreaterThan,
+    AsinReviewRatingLessThan,
+    AsinReviewRatingBetween,
+    AsinReviewRatingGreaterThan,
+    AsinSameAs,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SBTargetFilterType {
+    CreativeType,
+    TargetingState,
+    CampaignId,
+    AdGroupId,
+}
+
+// endregion
@@ -0,0 +1,55 @@
+use serde::Deserialize;
+
+// region SB v4 批量操作的错误结构
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBMutationError {
+    pub errors: Vec<SBMutationErrorDetail>,
+    pub index: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBMutationErrorDetail {
+    pub error_type: String,
+    pub error_value: SBErrorValue,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SBErrorValue {
+    AdEligibilityError(SBErrorSelector),
+    BiddingError(SBErrorSelector),
+    BillingError(SBErrorSelector),
+    DateError(SBErrorSelector),
+    DuplicateValueError(SBErrorSelector),
+    EntityNotFoundError(SBErrorSelector),
+    EntityQuotaError(SBErrorSelector),
+    EntityStateError(SBErrorSelector),
+    InternalServerError(SBErrorSelector),
+    MalformedValueError(SBErrorSelector),
+    MissingValueError(SBErrorSelector),
+    OtherError(SBErrorSelector),
+    ParentEntityError(SBErrorSelector),
+    RangeError(SBErrorSelector),
+    ThrottledError(SBErrorSelector),
+    UnsupportedOperationError(SBErrorSelector),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBErrorSelector {
+    pub cause: Option<SBErrorCause>,
+    pub message: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBErrorCause {
+    pub location: String,
+    pub trigger: Option<String>,
+}
+
+// endregion
@@ -0,0 +1,88 @@
+use super::enums::{SBKeywordMatchType, SBKeywordState, SBNegativeKeywordMatchType};
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+// region SBKeyword
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBKeyword {
+    pub ad_group_id: i64,
+    pub bid: Option<f64>,
+    pub campaign_id: i64,
+    pub keyword_id: i64,
+    pub keyword_text: String,
+    pub match_type: SBKeywordMatchType,
+    pub native_language_keyword: Option<String>,
+    pub native_language_locale: Option<String>,
+    pub state: SBKeywordState,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBKeywordCreate {
+    pub ad_group_id: i64,
+    pub bid: Option<f64>,
+    pub campaign_id: i64,
+    pub keyword_text: String,
+    pub match_type: SBKeywordMatchType,
+    pub native_language_keyword: Option<String>,
+    pub native_language_locale: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SBKeywordUpdate {
+    pub ad_group_id: i64,
+    pub bid: Option<f64>,
+    pub campaign_id: i64,
+    pub keyword_id: i64,
+    pub state: Option<SBKeywordState>,
+}
+
+// 创建/更新关键词返回的单条结果, code 为 SUCCESS 时表示成功
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBKeywordResponse {
+    pub ad_group_id: Option<i64>,
+    pub code: Option<String>,
+    pub details: Option<String>,
+    pub keyword_id: Option<i64>,
+}
+// endregion
+
+// region SBNegativeKeyword
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBNegativeKeyword {
+    pub ad_group_id: i64,
+    pub campaign_id: i64,
+    pub keyword_id: i64,
+    pub keyword_text: String,
+    pub match_type: SBNegativeKeywordMatchType,
+    pub state: SBKeywordState,
+}
+
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SBNegativeKeywordCreate {
+    pub ad_group_id: i64,
+    pub campaign_id: i64,
+    pub keyword_text: String,
+    pub match_type: SBNegativeKeywordMatchType,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SBNegativeKeywordUpdate {
+    pub ad_group_id: i64,
+    pub campaign_id: i64,
+    pub keyword_id: i64,
+    pub state: Option<SBKeywordState>,
+}
+// endregion
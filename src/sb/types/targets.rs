@@ -0,0 +1,121 @@
+use super::enums::{SBKeywordState, SBTargetFilterType, SBTargetingExpressionType};
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+// region SBTarget
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBTarget {
+    pub ad_group_id: i64,
+    pub bid: Option<f64>,
+    pub campaign_id: i64,
+    pub expressions: Vec<SBTargetingExpression>,
+    pub resolved_expressions: Option<Vec<SBTargetingExpression>>,
+    pub state: SBKeywordState,
+    pub target_id: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBTargetingExpression {
+    #[serde(rename = "type")]
+    pub expression_type: SBTargetingExpressionType,
+    pub value: String,
+}
+// endregion
+
+// region SBTargetCreate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SBTargetCreate {
+    #[builder(field)]
+    pub expressions: Vec<SBTargetingExpression>,
+    pub ad_group_id: i64,
+    pub bid: Option<f64>,
+    pub campaign_id: i64,
+}
+
+impl<S: s_b_target_create_builder::State> SBTargetCreateBuilder<S> {
+    pub fn expression(mut self, expression_type: SBTargetingExpressionType, value: &str) -> Self {
+        self.expressions.push(SBTargetingExpression {
+            expression_type,
+            value: value.to_string(),
+        });
+        self
+    }
+
+    pub fn asin(self, asin: &str) -> Self {
+        self.expression(SBTargetingExpressionType::AsinSameAs, asin)
+    }
+
+    pub fn category(self, category_id: &str) -> Self {
+        self.expression(SBTargetingExpressionType::AsinCategorySameAs, category_id)
+    }
+
+    pub fn brand(self, brand_id: &str) -> Self {
+        self.expression(SBTargetingExpressionType::AsinBrandSameAs, brand_id)
+    }
+}
+// endregion
+
+// region SBTargetUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SBTargetUpdate {
+    pub ad_group_id: i64,
+    pub bid: Option<f64>,
+    pub campaign_id: i64,
+    pub state: Option<SBKeywordState>,
+    pub target_id: i64,
+}
+// endregion
+
+// region ListTargets
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBTargetFilter {
+    pub filter_type: SBTargetFilterType,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListSBTargetsResponse {
+    pub next_token: Option<String>,
+    pub targets: Option<Vec<SBTarget>>,
+}
+// endregion
+
+// region OperationTargetsResponse
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBCreateTargetsResponse {
+    pub create_target_error_results: Option<Vec<SBTargetErrorResult>>,
+    pub create_target_success_results: Option<Vec<SBTargetSuccessResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBUpdateTargetsResponse {
+    pub update_target_error_results: Option<Vec<SBTargetErrorResult>>,
+    pub update_target_success_results: Option<Vec<SBTargetSuccessResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBTargetSuccessResult {
+    pub target_id: i64,
+    pub target_request_index: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SBTargetErrorResult {
+    pub code: Option<String>,
+    pub details: Option<String>,
+    pub target_request_index: usize,
+}
+// endregion
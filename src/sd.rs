@@ -0,0 +1,7 @@
+pub mod ad_groups;
+pub mod campaigns;
+pub mod creatives;
+pub mod negative_targets;
+pub mod product_ads;
+pub mod targets;
+pub mod types;
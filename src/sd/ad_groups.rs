@@ -0,0 +1,100 @@
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::Builder;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use super::campaigns::SDListFilter;
+use super::types::ad_groups::{SDAdGroup, SDAdGroupCreate, SDAdGroupUpdate};
+use super::types::SDResponse;
+
+// region ListAdGroups
+#[derive(Builder)]
+pub struct ListAdGroups {
+    ads_client: Arc<AdsClient>,
+    #[builder(default)]
+    filter: SDListFilter,
+}
+
+impl ListAdGroups {
+    pub async fn fetch(self) -> Result<Vec<SDAdGroup>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for ListAdGroups {
+    type Response = Vec<SDAdGroup>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/sd/adGroups".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        self.filter.to_query()
+    }
+}
+
+// endregion
+
+// region CreateAdGroups
+#[derive(Builder)]
+pub struct CreateAdGroups {
+    ads_client: Arc<AdsClient>,
+    ad_groups: Vec<SDAdGroupCreate>,
+}
+
+impl CreateAdGroups {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateAdGroups {
+    type Response = Vec<SDResponse>;
+
+    fn path(&self) -> String {
+        "/sd/adGroups".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.ad_groups)))
+    }
+}
+
+// endregion
+
+// region UpdateAdGroups
+#[derive(Builder)]
+pub struct UpdateAdGroups {
+    ads_client: Arc<AdsClient>,
+    ad_groups: Vec<SDAdGroupUpdate>,
+}
+
+impl UpdateAdGroups {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateAdGroups {
+    type Response = Vec<SDResponse>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sd/adGroups".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.ad_groups)))
+    }
+}
+
+// endregion
@@ -0,0 +1,153 @@
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::Builder;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use super::types::campaigns::{SDCampaign, SDCampaignCreate, SDCampaignUpdate};
+use super::types::enums::SDState;
+use super::types::SDResponse;
+
+// region ListCampaigns
+#[derive(Builder)]
+pub struct ListCampaigns {
+    ads_client: Arc<AdsClient>,
+    #[builder(default)]
+    filter: SDListFilter,
+}
+
+impl ListCampaigns {
+    pub async fn fetch(self) -> Result<Vec<SDCampaign>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for ListCampaigns {
+    type Response = Vec<SDCampaign>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/sd/campaigns".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        self.filter.to_query()
+    }
+}
+
+// SD 列表接口均为 GET 请求, 各接口支持的过滤字段不完全相同, 多个值用逗号分隔
+#[derive(Builder, Debug, Default)]
+#[builder(on(String, into))]
+pub struct SDListFilter {
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_group_id_filter: Option<Vec<String>>,
+
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub ad_id_filter: Option<Vec<String>>,
+
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub campaign_id_filter: Option<Vec<String>>,
+
+    pub count: Option<i32>,
+    pub name: Option<String>,
+
+    #[builder(default = 0)]
+    pub start_index: i32,
+    pub state_filter: Option<Vec<SDState>>,
+
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub target_id_filter: Option<Vec<String>>,
+}
+
+impl SDListFilter {
+    pub(crate) fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("startIndex", self.start_index.to_string())];
+        if let Some(count) = self.count {
+            query.push(("count", count.to_string()));
+        }
+        if let Some(ref ids) = self.ad_group_id_filter {
+            query.push(("adGroupIdFilter", ids.join(",")));
+        }
+        if let Some(ref ids) = self.ad_id_filter {
+            query.push(("adIdFilter", ids.join(",")));
+        }
+        if let Some(ref ids) = self.campaign_id_filter {
+            query.push(("campaignIdFilter", ids.join(",")));
+        }
+        if let Some(ref name) = self.name {
+            query.push(("name", name.clone()));
+        }
+        if let Some(ref states) = self.state_filter {
+            let states: Vec<String> = states.iter().map(|item| item.to_string()).collect();
+            query.push(("stateFilter", states.join(",")));
+        }
+        if let Some(ref ids) = self.target_id_filter {
+            query.push(("targetIdFilter", ids.join(",")));
+        }
+        query
+    }
+}
+
+// endregion
+
+// region CreateCampaigns
+#[derive(Builder)]
+pub struct CreateCampaigns {
+    ads_client: Arc<AdsClient>,
+    campaigns: Vec<SDCampaignCreate>,
+}
+
+impl CreateCampaigns {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateCampaigns {
+    type Response = Vec<SDResponse>;
+
+    fn path(&self) -> String {
+        "/sd/campaigns".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.campaigns)))
+    }
+}
+
+// endregion
+
+// region UpdateCampaigns
+#[derive(Builder)]
+pub struct UpdateCampaigns {
+    ads_client: Arc<AdsClient>,
+    campaigns: Vec<SDCampaignUpdate>,
+}
+
+impl UpdateCampaigns {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateCampaigns {
+    type Response = Vec<SDResponse>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sd/campaigns".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.campaigns)))
+    }
+}
+
+// endregion
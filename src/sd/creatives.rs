@@ -0,0 +1,100 @@
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::Builder;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use super::campaigns::SDListFilter;
+use super::types::creatives::{SDCreative, SDCreativeCreate, SDCreativeUpdate};
+use super::types::SDResponse;
+
+// region ListCreatives
+#[derive(Builder)]
+pub struct ListCreatives {
+    ads_client: Arc<AdsClient>,
+    #[builder(default)]
+    filter: SDListFilter,
+}
+
+impl ListCreatives {
+    pub async fn fetch(self) -> Result<Vec<SDCreative>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for ListCreatives {
+    type Response = Vec<SDCreative>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/sd/creatives".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        self.filter.to_query()
+    }
+}
+
+// endregion
+
+// region CreateCreatives
+#[derive(Builder)]
+pub struct CreateCreatives {
+    ads_client: Arc<AdsClient>,
+    creatives: Vec<SDCreativeCreate>,
+}
+
+impl CreateCreatives {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateCreatives {
+    type Response = Vec<SDResponse>;
+
+    fn path(&self) -> String {
+        "/sd/creatives".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.creatives)))
+    }
+}
+
+// endregion
+
+// region UpdateCreatives
+#[derive(Builder)]
+pub struct UpdateCreatives {
+    ads_client: Arc<AdsClient>,
+    creatives: Vec<SDCreativeUpdate>,
+}
+
+impl UpdateCreatives {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateCreatives {
+    type Response = Vec<SDResponse>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sd/creatives".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.creatives)))
+    }
+}
+
+// endregion
@@ -0,0 +1,100 @@
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::Builder;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use super::campaigns::SDListFilter;
+use super::types::targets::{SDNegativeTarget, SDNegativeTargetCreate, SDNegativeTargetUpdate};
+use super::types::SDResponse;
+
+// region ListNegativeTargets
+#[derive(Builder)]
+pub struct ListNegativeTargets {
+    ads_client: Arc<AdsClient>,
+    #[builder(default)]
+    filter: SDListFilter,
+}
+
+impl ListNegativeTargets {
+    pub async fn fetch(self) -> Result<Vec<SDNegativeTarget>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for ListNegativeTargets {
+    type Response = Vec<SDNegativeTarget>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/sd/negativeTargets".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        self.filter.to_query()
+    }
+}
+
+// endregion
+
+// region CreateNegativeTargets
+#[derive(Builder)]
+pub struct CreateNegativeTargets {
+    ads_client: Arc<AdsClient>,
+    negative_targets: Vec<SDNegativeTargetCreate>,
+}
+
+impl CreateNegativeTargets {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateNegativeTargets {
+    type Response = Vec<SDResponse>;
+
+    fn path(&self) -> String {
+        "/sd/negativeTargets".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.negative_targets)))
+    }
+}
+
+// endregion
+
+// region UpdateNegativeTargets
+#[derive(Builder)]
+pub struct UpdateNegativeTargets {
+    ads_client: Arc<AdsClient>,
+    negative_targets: Vec<SDNegativeTargetUpdate>,
+}
+
+impl UpdateNegativeTargets {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateNegativeTargets {
+    type Response = Vec<SDResponse>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sd/negativeTargets".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.negative_targets)))
+    }
+}
+
+// endregion
@@ -0,0 +1,100 @@
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::Builder;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use super::campaigns::SDListFilter;
+use super::types::product_ads::{SDProductAd, SDProductAdCreate, SDProductAdUpdate};
+use super::types::SDResponse;
+
+// region ListProductAds
+#[derive(Builder)]
+pub struct ListProductAds {
+    ads_client: Arc<AdsClient>,
+    #[builder(default)]
+    filter: SDListFilter,
+}
+
+impl ListProductAds {
+    pub async fn fetch(self) -> Result<Vec<SDProductAd>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for ListProductAds {
+    type Response = Vec<SDProductAd>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/sd/productAds".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        self.filter.to_query()
+    }
+}
+
+// endregion
+
+// region CreateProductAds
+#[derive(Builder)]
+pub struct CreateProductAds {
+    ads_client: Arc<AdsClient>,
+    product_ads: Vec<SDProductAdCreate>,
+}
+
+impl CreateProductAds {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateProductAds {
+    type Response = Vec<SDResponse>;
+
+    fn path(&self) -> String {
+        "/sd/productAds".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.product_ads)))
+    }
+}
+
+// endregion
+
+// region UpdateProductAds
+#[derive(Builder)]
+pub struct UpdateProductAds {
+    ads_client: Arc<AdsClient>,
+    product_ads: Vec<SDProductAdUpdate>,
+}
+
+impl UpdateProductAds {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateProductAds {
+    type Response = Vec<SDResponse>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sd/productAds".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.product_ads)))
+    }
+}
+
+// endregion
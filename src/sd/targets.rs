@@ -0,0 +1,100 @@
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::Builder;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use super::campaigns::SDListFilter;
+use super::types::targets::{SDTarget, SDTargetCreate, SDTargetUpdate};
+use super::types::SDResponse;
+
+// region ListTargets
+#[derive(Builder)]
+pub struct ListTargets {
+    ads_client: Arc<AdsClient>,
+    #[builder(default)]
+    filter: SDListFilter,
+}
+
+impl ListTargets {
+    pub async fn fetch(self) -> Result<Vec<SDTarget>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for ListTargets {
+    type Response = Vec<SDTarget>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/sd/targets".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        self.filter.to_query()
+    }
+}
+
+// endregion
+
+// region CreateTargets
+#[derive(Builder)]
+pub struct CreateTargets {
+    ads_client: Arc<AdsClient>,
+    targets: Vec<SDTargetCreate>,
+}
+
+impl CreateTargets {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateTargets {
+    type Response = Vec<SDResponse>;
+
+    fn path(&self) -> String {
+        "/sd/targets".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.targets)))
+    }
+}
+
+// endregion
+
+// region UpdateTargets
+#[derive(Builder)]
+pub struct UpdateTargets {
+    ads_client: Arc<AdsClient>,
+    targets: Vec<SDTargetUpdate>,
+}
+
+impl UpdateTargets {
+    pub async fn fetch(self) -> Result<Vec<SDResponse>> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateTargets {
+    type Response = Vec<SDResponse>;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sd/targets".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!(self.targets)))
+    }
+}
+
+// endregion
@@ -0,0 +1,27 @@
+use serde::Deserialize;
+
+pub mod ad_groups;
+pub mod campaigns;
+pub mod creatives;
+pub mod enums;
+pub mod product_ads;
+pub mod targets;
+
+// SD 创建/更新接口统一返回每条记录的结果, code 为 SUCCESS 时表示成功, 对应实体 id 只会出现一个
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SDResponse {
+    pub ad_group_id: Option<i64>,
+    pub ad_id: Option<i64>,
+    pub campaign_id: Option<i64>,
+    pub code: String,
+    pub creative_id: Option<i64>,
+    pub description: Option<String>,
+    pub target_id: Option<i64>,
+}
+
+impl SDResponse {
+    pub fn is_success(&self) -> bool {
+        self.code == "SUCCESS"
+    }
+}
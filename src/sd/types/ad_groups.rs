@@ -0,0 +1,57 @@
+use super::enums::{SDBidOptimization, SDCreativeType, SDState, SDTactic};
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+// region SDAdGroup
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SDAdGroup {
+    pub ad_group_id: i64,
+    pub bid_optimization: Option<SDBidOptimization>,
+    pub campaign_id: i64,
+    pub creative_type: Option<SDCreativeType>,
+    pub default_bid: f64,
+    pub name: String,
+    pub state: SDState,
+    pub tactic: Option<SDTactic>,
+}
+
+// endregion
+
+// region SDAdGroupCreate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SDAdGroupCreate {
+    #[builder(start_fn)]
+    pub campaign_id: i64,
+
+    // 不传时按广告活动 costType 默认: cpc -> clicks, vcpm -> reach
+    pub bid_optimization: Option<SDBidOptimization>,
+    pub creative_type: Option<SDCreativeType>,
+    pub default_bid: f64,
+    pub name: String,
+
+    #[builder(default=SDState::Enabled)]
+    pub state: SDState,
+}
+
+// endregion
+
+// region SDAdGroupUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SDAdGroupUpdate {
+    #[builder(start_fn)]
+    pub ad_group_id: i64,
+    pub bid_optimization: Option<SDBidOptimization>,
+    pub default_bid: Option<f64>,
+    pub name: Option<String>,
+    pub state: Option<SDState>,
+}
+
+// endregion
@@ -0,0 +1,72 @@
+use super::enums::{SDBudgetType, SDCostType, SDState, SDTactic};
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+// region SDCampaign
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SDCampaign {
+    pub budget: f64,
+    pub budget_type: SDBudgetType,
+    pub campaign_id: i64,
+    pub cost_type: Option<SDCostType>,
+    pub end_date: Option<String>,
+    pub name: String,
+    pub portfolio_id: Option<i64>,
+    pub start_date: String,
+    pub state: SDState,
+    pub tactic: SDTactic,
+}
+
+// endregion
+
+// region SDCampaignCreate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SDCampaignCreate {
+    #[builder(start_fn)]
+    pub name: String,
+
+    pub budget: f64,
+
+    #[builder(default=SDBudgetType::Daily)]
+    pub budget_type: SDBudgetType,
+
+    #[builder(default=SDCostType::Cpc)]
+    pub cost_type: SDCostType,
+
+    // 格式 YYYYMMDD
+    pub end_date: Option<String>,
+    pub portfolio_id: Option<i64>,
+
+    // 格式 YYYYMMDD
+    pub start_date: String,
+
+    #[builder(default=SDState::Enabled)]
+    pub state: SDState,
+
+    pub tactic: SDTactic,
+}
+
+// endregion
+
+// region SDCampaignUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SDCampaignUpdate {
+    #[builder(start_fn)]
+    pub campaign_id: i64,
+    pub budget: Option<f64>,
+    pub end_date: Option<String>,
+    pub name: Option<String>,
+    pub portfolio_id: Option<i64>,
+    pub start_date: Option<String>,
+    pub state: Option<SDState>,
+}
+
+// endregion
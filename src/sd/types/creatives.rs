@@ -0,0 +1,81 @@
+use super::enums::SDCreativeType;
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+// region SDCreative
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SDCreative {
+    pub ad_group_id: i64,
+    pub creative_id: i64,
+    pub creative_type: Option<SDCreativeType>,
+    pub moderation_status: Option<String>,
+    pub properties: SDCreativeProperties,
+}
+
+// 创意属性, 读写共用. 不传的字段由系统根据推广商品自动生成
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SDCreativeProperties {
+    pub brand_logo: Option<SDImageAsset>,
+    pub custom_image: Option<SDImageAsset>,
+    pub headline: Option<String>,
+    pub video: Option<SDVideoAsset>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SDImageAsset {
+    #[builder(start_fn)]
+    pub asset_id: String,
+    #[builder(start_fn)]
+    pub asset_version: String,
+    pub cropping_coordinates: Option<SDCroppingCoordinates>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SDCroppingCoordinates {
+    pub height: i32,
+    pub left: i32,
+    pub top: i32,
+    pub width: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SDVideoAsset {
+    pub asset_id: String,
+    pub asset_version: String,
+}
+
+// endregion
+
+// region SDCreativeCreate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SDCreativeCreate {
+    #[builder(start_fn)]
+    pub ad_group_id: i64,
+    pub creative_type: Option<SDCreativeType>,
+    pub properties: SDCreativeProperties,
+}
+
+// endregion
+
+// region SDCreativeUpdate
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SDCreativeUpdate {
+    #[builder(start_fn)]
+    pub creative_id: i64,
+    pub properties: SDCreativeProperties,
+}
+
+// endregion
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumString};
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SDState {
+    Enabled,
+    Paused,
+    Archived,
+}
+
+// 广告策略
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+pub enum SDTactic {
+    T00020, // 内容相关投放 (商品/品类)
+    T00030, // 受众投放 (浏览/购买再营销, 场内/生活方式受众)
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SDCostType {
+    Cpc,
+    Vcpm,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SDBudgetType {
+    Daily,
+}
+
+// 广告组竞价优化目标
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+#[serde(rename_all = "camelCase")]
+pub enum SDBidOptimization {
+    Clicks,      // 优化点击, 仅 cpc
+    Conversions, // 优化转化, 仅 cpc
+    Reach,       // 优化曝光, 仅 vcpm
+    Leads,
+    PageVisits,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SDCreativeType {
+    Image,
+    Video,
+}
+
+#[derive(Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SDExpressionType {
+    Manual,
+    Auto,
+}
+
+// 投放表达式谓词类型, 顶层与嵌套表达式共用
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq, Eq,
+)]
+#[strum(serialize_all = "camelCase")]
+#[serde(rename_all = "camelCase")]
+pub enum SDPredicateType {
+    // 内容相关 - 商品/品类
+    AsinSameAs,
+    AsinCategorySameAs,
+    AsinBrandSameAs,
+    AsinPriceBetween,
+    AsinPriceGreaterThan,
+    AsinPriceLessThan,
+    AsinReviewRatingBetween,
+    AsinReviewRatingGreaterThan,
+    AsinReviewRatingLessThan,
+    AsinIsPrimeShippingEligible,
+    AsinAgeRangeSameAs,
+    AsinGenreSameAs,
+    SimilarProduct,
+
+    // 再营销 - 顶层
+    Views,
+    Purchases,
+
+    // 受众 - 顶层
+    Audience,
+
+    // 再营销嵌套
+    ExactProduct,
+    RelatedProduct,
+    Lookback,
+
+    // 受众嵌套, value 为场内/生活方式受众 id
+    AudienceSameAs,
+
+    #[serde(other)]
+    Other,
+}
@@ -0,0 +1,50 @@
+use super::enums::SDState;
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+// region SDProductAd
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SDProductAd {
+    pub ad_group_id: i64,
+    pub ad_id: i64,
+    pub ad_name: Option<String>,
+    pub asin: Option<String>,
+    pub campaign_id: i64,
+    pub sku: Option<String>,
+    pub state: SDState,
+}
+
+// endregion
+
+// region SDProductAdCreate
+// 卖家填 sku, 供应商填 asin
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct SDProductAdCreate {
+    #[builder(start_fn)]
+    pub ad_group_id: i64,
+    pub ad_name: Option<String>,
+    pub asin: Option<String>,
+    pub sku: Option<String>,
+
+    #[builder(default=SDState::Enabled)]
+    pub state: SDState,
+}
+
+// endregion
+
+// region SDProductAdUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SDProductAdUpdate {
+    #[builder(start_fn)]
+    pub ad_id: i64,
+    pub state: SDState,
+}
+
+// endregion
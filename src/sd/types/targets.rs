@@ -0,0 +1,257 @@
+use super::enums::{SDExpressionType, SDPredicateType, SDState};
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+// region SDTargetingPredicate
+// 投放表达式中的单个条件, 同一表达式内的多个条件为 AND 关系
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SDTargetingPredicate {
+    #[serde(rename = "type")]
+    pub predicate_type: SDPredicateType,
+    pub value: Option<SDPredicateValue>,
+}
+
+// 内容相关投放 value 为字符串, 再营销/受众投放 value 为嵌套条件
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SDPredicateValue {
+    Text(String),
+    Nested(Vec<SDTargetingPredicateNested>),
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SDTargetingPredicateNested {
+    #[serde(rename = "type")]
+    pub predicate_type: SDPredicateType,
+    pub value: Option<String>,
+}
+
+impl SDTargetingPredicateNested {
+    pub fn exact_product() -> Self {
+        Self {
+            predicate_type: SDPredicateType::ExactProduct,
+            value: None,
+        }
+    }
+
+    pub fn related_product() -> Self {
+        Self {
+            predicate_type: SDPredicateType::RelatedProduct,
+            value: None,
+        }
+    }
+
+    pub fn similar_product() -> Self {
+        Self {
+            predicate_type: SDPredicateType::SimilarProduct,
+            value: None,
+        }
+    }
+
+    pub fn category(category_id: &str) -> Self {
+        Self {
+            predicate_type: SDPredicateType::AsinCategorySameAs,
+            value: Some(category_id.to_string()),
+        }
+    }
+
+    fn lookback(days: i32) -> Self {
+        Self {
+            predicate_type: SDPredicateType::Lookback,
+            value: Some(days.to_string()),
+        }
+    }
+}
+
+impl SDTargetingPredicate {
+    fn text(predicate_type: SDPredicateType, value: &str) -> Self {
+        Self {
+            predicate_type,
+            value: Some(SDPredicateValue::Text(value.to_string())),
+        }
+    }
+
+    fn nested(predicate_type: SDPredicateType, value: Vec<SDTargetingPredicateNested>) -> Self {
+        Self {
+            predicate_type,
+            value: Some(SDPredicateValue::Nested(value)),
+        }
+    }
+}
+
+// endregion
+
+// region SDTarget
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SDTarget {
+    pub ad_group_id: i64,
+    pub bid: Option<f64>,
+    pub expression: Vec<SDTargetingPredicate>,
+    pub expression_type: SDExpressionType,
+    pub resolved_expression: Option<Vec<SDTargetingPredicate>>,
+    pub state: SDState,
+    pub target_id: i64,
+}
+
+// endregion
+
+// region SDTargetCreate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SDTargetCreate {
+    #[builder(start_fn)]
+    pub ad_group_id: i64,
+
+    #[builder(field)]
+    pub expression: Vec<SDTargetingPredicate>,
+
+    pub bid: Option<f64>,
+
+    #[builder(default=SDExpressionType::Manual)]
+    pub expression_type: SDExpressionType,
+
+    #[builder(default=SDState::Enabled)]
+    pub state: SDState,
+}
+
+impl<S: s_d_target_create_builder::State> SDTargetCreateBuilder<S> {
+    pub fn predicate(mut self, predicate: SDTargetingPredicate) -> Self {
+        self.expression.push(predicate);
+        self
+    }
+
+    /// 内容相关 - 指定商品 (T00020)
+    pub fn asin(self, asin: &str) -> Self {
+        self.predicate(SDTargetingPredicate::text(
+            SDPredicateType::AsinSameAs,
+            asin,
+        ))
+    }
+
+    /// 内容相关 - 指定品类 (T00020), 可与 brand 等条件组合细化
+    pub fn category(self, category_id: &str) -> Self {
+        self.predicate(SDTargetingPredicate::text(
+            SDPredicateType::AsinCategorySameAs,
+            category_id,
+        ))
+    }
+
+    pub fn brand(self, brand_id: &str) -> Self {
+        self.predicate(SDTargetingPredicate::text(
+            SDPredicateType::AsinBrandSameAs,
+            brand_id,
+        ))
+    }
+
+    /// 内容相关 - 与推广商品相似的商品 (T00020)
+    pub fn similar_product(self) -> Self {
+        self.predicate(SDTargetingPredicate {
+            predicate_type: SDPredicateType::SimilarProduct,
+            value: None,
+        })
+    }
+
+    /// 浏览再营销 (T00030), lookback_days 可选 7/14/30/60/90
+    pub fn views(self, product: SDTargetingPredicateNested, lookback_days: i32) -> Self {
+        self.predicate(SDTargetingPredicate::nested(
+            SDPredicateType::Views,
+            vec![product, SDTargetingPredicateNested::lookback(lookback_days)],
+        ))
+    }
+
+    /// 购买再营销 (T00030), lookback_days 可选 7/14/30/60/90/180/365
+    pub fn purchases(self, product: SDTargetingPredicateNested, lookback_days: i32) -> Self {
+        self.predicate(SDTargetingPredicate::nested(
+            SDPredicateType::Purchases,
+            vec![product, SDTargetingPredicateNested::lookback(lookback_days)],
+        ))
+    }
+
+    /// 场内/生活方式等受众 (T00030)
+    pub fn audience(self, audience_id: &str) -> Self {
+        self.predicate(SDTargetingPredicate::nested(
+            SDPredicateType::Audience,
+            vec![SDTargetingPredicateNested {
+                predicate_type: SDPredicateType::AudienceSameAs,
+                value: Some(audience_id.to_string()),
+            }],
+        ))
+    }
+}
+
+// endregion
+
+// region SDTargetUpdate
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SDTargetUpdate {
+    #[builder(start_fn)]
+    pub target_id: i64,
+    pub bid: Option<f64>,
+    pub state: Option<SDState>,
+}
+
+// endregion
+
+// region SDNegativeTarget
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SDNegativeTarget {
+    pub ad_group_id: i64,
+    pub expression: Vec<SDTargetingPredicate>,
+    pub expression_type: SDExpressionType,
+    pub state: SDState,
+    pub target_id: i64,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SDNegativeTargetCreate {
+    #[builder(start_fn)]
+    pub ad_group_id: i64,
+
+    #[builder(field)]
+    pub expression: Vec<SDTargetingPredicate>,
+
+    #[builder(default=SDExpressionType::Manual)]
+    pub expression_type: SDExpressionType,
+
+    #[builder(default=SDState::Enabled)]
+    pub state: SDState,
+}
+
+impl<S: s_d_negative_target_create_builder::State> SDNegativeTargetCreateBuilder<S> {
+    pub fn asin(mut self, asin: &str) -> Self {
+        self.expression.push(SDTargetingPredicate::text(
+            SDPredicateType::AsinSameAs,
+            asin,
+        ));
+        self
+    }
+
+    pub fn brand(mut self, brand_id: &str) -> Self {
+        self.expression.push(SDTargetingPredicate::text(
+            SDPredicateType::AsinBrandSameAs,
+            brand_id,
+        ));
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SDNegativeTargetUpdate {
+    #[builder(start_fn)]
+    pub target_id: i64,
+    pub state: SDState,
+}
+
+// endregion
@@ -0,0 +1,3 @@
+pub mod aggregator;
+pub mod messages;
+pub mod subscriptions;
@@ -0,0 +1,319 @@
+use super::messages::{SPConversionMessage, StreamMessage, TrafficMessage};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, DurationRound, FixedOffset, NaiveDate, TimeDelta, Utc};
+use chrono_tz::Tz;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::ops::AddAssign;
+
+// region HourlyAggregator
+// 转化数据最长 30 天归因, 多留一天
+const DEFAULT_SEEN_RETENTION_DAYS: i64 = 31;
+
+/// 汇总 sp-traffic / sp-conversion 消息为按小时的指标
+/// - 按 idempotency_id 去重, SQS 至少投递一次
+/// - 消息是增量(含补发和修正的负数), 同一小时直接累加, 迟到的消息照样计入
+/// - 按 UTC 整点分桶, 按广告主时区换算自然日, 与报告的 date 对齐
+#[derive(Debug)]
+pub struct HourlyAggregator {
+    timezone: Tz,
+    // idempotency_id -> time_window_start, 超出保留期的会被清理
+    seen: HashMap<String, DateTime<Utc>>,
+    seen_retention: TimeDelta,
+    latest_window: Option<DateTime<Utc>>,
+    metrics: BTreeMap<HourlyKey, HourlyMetrics>,
+    // 上次 take_updated 之后有变动的小时, 用于把修正写回存储
+    updated: BTreeSet<HourlyKey>,
+}
+
+impl HourlyAggregator {
+    pub fn new(timezone: Tz) -> Self {
+        Self {
+            timezone,
+            seen: HashMap::new(),
+            seen_retention: TimeDelta::days(DEFAULT_SEEN_RETENTION_DAYS),
+            latest_window: None,
+            metrics: BTreeMap::new(),
+            updated: BTreeSet::new(),
+        }
+    }
+
+    /// 时区使用 profile 的 timezone, 例如 Europe/London
+    pub fn from_timezone(timezone: &str) -> Result<Self> {
+        let timezone = timezone
+            .parse::<Tz>()
+            .map_err(|_| anyhow!("无效的时区: {}", timezone))?;
+        Ok(Self::new(timezone))
+    }
+
+    /// 去重 id 的保留期, 按 time_window_start 计算, 默认 31 天
+    /// 早于 最新 time_window_start - 保留期 的 id 会被清理, 之后重投的同一消息无法再去重
+    pub fn seen_retention(mut self, retention: TimeDelta) -> Self {
+        self.seen_retention = retention;
+        self
+    }
+
+    /// 当前保留的去重 id 数量
+    pub fn seen_len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// 返回 false 表示重复消息或不支持的数据集
+    pub fn push(&mut self, message: &StreamMessage) -> bool {
+        match message {
+            StreamMessage::SpTraffic(message) => self.push_traffic(message),
+            StreamMessage::SpConversion(message) => self.push_conversion(message),
+            _ => false,
+        }
+    }
+
+    pub fn push_traffic(&mut self, message: &TrafficMessage) -> bool {
+        if !self.first_seen(&message.idempotency_id, &message.time_window_start) {
+            return false;
+        }
+        let key = self.key(
+            &message.campaign_id,
+            &message.ad_group_id,
+            &message.keyword_id,
+            &message.time_window_start,
+        );
+        let metrics = HourlyMetrics {
+            impressions: message.impressions,
+            clicks: message.clicks,
+            cost: message.cost,
+            ..Default::default()
+        };
+        self.apply(key, metrics);
+        true
+    }
+
+    pub fn push_conversion(&mut self, message: &SPConversionMessage) -> bool {
+        if !self.first_seen(&message.idempotency_id, &message.time_window_start) {
+            return false;
+        }
+        let key = self.key(
+            &message.campaign_id,
+            &message.ad_group_id,
+            &message.keyword_id,
+            &message.time_window_start,
+        );
+        let metrics = HourlyMetrics {
+            purchases_7d: message.attributed_conversions_7d,
+            purchases_14d: message.attributed_conversions_14d,
+            units_sold_7d: message.attributed_units_ordered_7d,
+            units_sold_14d: message.attributed_units_ordered_14d,
+            sales_7d: message.attributed_sales_7d,
+            sales_14d: message.attributed_sales_14d,
+            ..Default::default()
+        };
+        self.apply(key, metrics);
+        true
+    }
+
+    pub fn get(&self, key: &HourlyKey) -> Option<&HourlyMetrics> {
+        self.metrics.get(key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&HourlyKey, &HourlyMetrics)> {
+        self.metrics.iter()
+    }
+
+    /// 取出上次调用以来变动过的小时(含迟到/修正的数据)及其最新累计值
+    pub fn take_updated(&mut self) -> Vec<(HourlyKey, HourlyMetrics)> {
+        std::mem::take(&mut self.updated)
+            .into_iter()
+            .filter_map(|key| self.metrics.get(&key).map(|metrics| (key, metrics.clone())))
+            .collect()
+    }
+
+    /// 按广告主时区的自然日汇总
+    pub fn daily(&self) -> BTreeMap<DailyKey, HourlyMetrics> {
+        let mut daily: BTreeMap<DailyKey, HourlyMetrics> = BTreeMap::new();
+        for (key, metrics) in &self.metrics {
+            *daily.entry(key.daily_key()).or_default() += metrics;
+        }
+        daily
+    }
+
+    /// 与报告(GetReport::fetch_document 的行)按 日期/广告活动/广告组/关键词 对齐
+    /// 报告行没有的维度不参与匹配, 例如 spCampaigns 只按 日期+广告活动
+    pub fn join_report_rows(&self, rows: &[Value]) -> Vec<DailyJoin> {
+        let daily = self.daily();
+        rows.iter()
+            .filter_map(|row| {
+                let (key, report) = DailyKey::from_report_row(row)?;
+                let mut stream = None;
+                for (daily_key, metrics) in &daily {
+                    if key.matches(daily_key) {
+                        *stream.get_or_insert_with(HourlyMetrics::default) += metrics;
+                    }
+                }
+                Some(DailyJoin {
+                    key,
+                    report,
+                    stream,
+                })
+            })
+            .collect()
+    }
+
+    fn first_seen(
+        &mut self,
+        idempotency_id: &str,
+        time_window_start: &DateTime<FixedOffset>,
+    ) -> bool {
+        if self.seen.contains_key(idempotency_id) {
+            return false;
+        }
+        let window = time_window_start.with_timezone(&Utc);
+        self.seen.insert(idempotency_id.to_string(), window);
+        // 最新的小时推进时才清理, 避免每条消息都遍历
+        if self.latest_window.is_none_or(|latest| window > latest) {
+            self.latest_window = Some(window);
+            let cutoff = window - self.seen_retention;
+            self.seen.retain(|_, seen_window| *seen_window >= cutoff);
+        }
+        true
+    }
+
+    fn key(
+        &self,
+        campaign_id: &str,
+        ad_group_id: &Option<String>,
+        target_id: &Option<String>,
+        time_window_start: &DateTime<FixedOffset>,
+    ) -> HourlyKey {
+        // 按 UTC 截断, 夏令时回拨当天本地时间重复的两个小时不会落到同一个桶
+        let utc = time_window_start.with_timezone(&Utc);
+        let hour = utc.duration_trunc(TimeDelta::hours(1)).unwrap_or(utc);
+        HourlyKey {
+            hour: hour.with_timezone(&self.timezone),
+            campaign_id: campaign_id.to_string(),
+            ad_group_id: ad_group_id.clone(),
+            target_id: target_id.clone(),
+        }
+    }
+
+    fn apply(&mut self, key: HourlyKey, metrics: HourlyMetrics) {
+        *self.metrics.entry(key.clone()).or_default() += &metrics;
+        self.updated.insert(key);
+    }
+}
+
+// endregion
+
+// region 指标与维度
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HourlyKey {
+    // 整点, 按 UTC 时刻比较, 以广告主时区表示
+    pub hour: DateTime<Tz>,
+    pub campaign_id: String,
+    pub ad_group_id: Option<String>,
+    // 关键词或商品投放的 id
+    pub target_id: Option<String>,
+}
+
+impl HourlyKey {
+    pub fn daily_key(&self) -> DailyKey {
+        DailyKey {
+            date: self.hour.date_naive(),
+            campaign_id: self.campaign_id.clone(),
+            ad_group_id: self.ad_group_id.clone(),
+            target_id: self.target_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DailyKey {
+    pub date: NaiveDate,
+    pub campaign_id: String,
+    pub ad_group_id: Option<String>,
+    pub target_id: Option<String>,
+}
+
+impl DailyKey {
+    /// 报告行使用 camelCase 字段: date, campaignId, adGroupId, keywordId
+    pub fn from_report_row(row: &Value) -> Option<(Self, HourlyMetrics)> {
+        let date = row.get("date")?.as_str()?.parse::<NaiveDate>().ok()?;
+        let key = DailyKey {
+            date,
+            campaign_id: report_id(row, "campaignId")?,
+            ad_group_id: report_id(row, "adGroupId"),
+            target_id: report_id(row, "keywordId"),
+        };
+        Some((key, HourlyMetrics::from_report_row(row)))
+    }
+
+    // None 的维度视为汇总
+    fn matches(&self, other: &DailyKey) -> bool {
+        self.date == other.date
+            && self.campaign_id == other.campaign_id
+            && (self.ad_group_id.is_none() || self.ad_group_id == other.ad_group_id)
+            && (self.target_id.is_none() || self.target_id == other.target_id)
+    }
+}
+
+// 报告中的 id 可能是数字
+fn report_id(row: &Value, field: &str) -> Option<String> {
+    match row.get(field)? {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HourlyMetrics {
+    pub impressions: i64,
+    pub clicks: i64,
+    pub cost: f64,
+    pub purchases_7d: i64,
+    pub purchases_14d: i64,
+    pub units_sold_7d: i64,
+    pub units_sold_14d: i64,
+    pub sales_7d: f64,
+    pub sales_14d: f64,
+}
+
+impl HourlyMetrics {
+    fn from_report_row(row: &Value) -> Self {
+        let int = |field: &str| row.get(field).and_then(Value::as_i64).unwrap_or_default();
+        let float = |field: &str| row.get(field).and_then(Value::as_f64).unwrap_or_default();
+        Self {
+            impressions: int("impressions"),
+            clicks: int("clicks"),
+            cost: float("cost"),
+            purchases_7d: int("purchases7d"),
+            purchases_14d: int("purchases14d"),
+            units_sold_7d: int("unitsSoldClicks7d"),
+            units_sold_14d: int("unitsSoldClicks14d"),
+            sales_7d: float("sales7d"),
+            sales_14d: float("sales14d"),
+        }
+    }
+}
+
+impl AddAssign<&HourlyMetrics> for HourlyMetrics {
+    fn add_assign(&mut self, other: &HourlyMetrics) {
+        self.impressions += other.impressions;
+        self.clicks += other.clicks;
+        self.cost += other.cost;
+        self.purchases_7d += other.purchases_7d;
+        self.purchases_14d += other.purchases_14d;
+        self.units_sold_7d += other.units_sold_7d;
+        self.units_sold_14d += other.units_sold_14d;
+        self.sales_7d += other.sales_7d;
+        self.sales_14d += other.sales_14d;
+    }
+}
+
+#[derive(Debug)]
+pub struct DailyJoin {
+    pub key: DailyKey,
+    pub report: HourlyMetrics,
+    // 没有收到该维度的 stream 消息时为 None
+    pub stream: Option<HourlyMetrics>,
+}
+
+// endregion
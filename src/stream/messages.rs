@@ -0,0 +1,366 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+// Marketing Stream 推送的消息字段均为 snake_case
+
+// region StreamMessage
+/// 按 dataset_id 解析后的消息
+#[derive(Debug)]
+pub enum StreamMessage {
+    SpTraffic(TrafficMessage),
+    SpConversion(SPConversionMessage),
+    SbTraffic(TrafficMessage),
+    SbConversion(SBConversionMessage),
+    SdTraffic(TrafficMessage),
+    SdConversion(SDConversionMessage),
+    BudgetUsage(BudgetUsageMessage),
+    SpBudgetRecommendations(SPBudgetRecommendationMessage),
+    SpCampaigns(SPCampaignMessage),
+    SpAdgroups(SPAdGroupMessage),
+    SpAds(SPAdMessage),
+    SpTargets(SPTargetMessage),
+    // 未收录的数据集保留原始 JSON
+    Other { dataset_id: String, value: Value },
+}
+
+impl StreamMessage {
+    pub fn from_value(value: Value) -> Result<Self> {
+        let dataset_id = value
+            .get("dataset_id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow!("消息缺少 dataset_id"))?
+            .to_string();
+        let message = match dataset_id.as_str() {
+            "sp-traffic" => StreamMessage::SpTraffic(serde_json::from_value(value)?),
+            "sp-conversion" => StreamMessage::SpConversion(serde_json::from_value(value)?),
+            "sb-traffic" => StreamMessage::SbTraffic(serde_json::from_value(value)?),
+            "sb-conversion" => StreamMessage::SbConversion(serde_json::from_value(value)?),
+            "sd-traffic" => StreamMessage::SdTraffic(serde_json::from_value(value)?),
+            "sd-conversion" => StreamMessage::SdConversion(serde_json::from_value(value)?),
+            "budget-usage" => StreamMessage::BudgetUsage(serde_json::from_value(value)?),
+            "sp-budget-recommendations" => {
+                StreamMessage::SpBudgetRecommendations(serde_json::from_value(value)?)
+            }
+            "sp-campaigns" => StreamMessage::SpCampaigns(serde_json::from_value(value)?),
+            "sp-adgroups" => StreamMessage::SpAdgroups(serde_json::from_value(value)?),
+            "sp-ads" => StreamMessage::SpAds(serde_json::from_value(value)?),
+            "sp-targets" => StreamMessage::SpTargets(serde_json::from_value(value)?),
+            _ => StreamMessage::Other { dataset_id, value },
+        };
+        Ok(message)
+    }
+
+    /// SQS 消息体, 一条消息一个 JSON
+    pub fn from_json(body: &str) -> Result<Self> {
+        Self::from_value(serde_json::from_str(body)?)
+    }
+
+    /// Firehose 落地的文件, 多个 JSON 首尾相连或按行分隔
+    pub fn from_json_records(data: &str) -> Result<Vec<Self>> {
+        serde_json::Deserializer::from_str(data)
+            .into_iter::<Value>()
+            .map(|value| Self::from_value(value?))
+            .collect()
+    }
+}
+
+// endregion
+
+// region 流量 sp-traffic / sb-traffic / sd-traffic
+#[derive(Deserialize, Debug, Clone)]
+pub struct TrafficMessage {
+    pub idempotency_id: String,
+    pub dataset_id: String,
+    pub marketplace_id: String,
+    pub currency: String,
+    pub advertiser_id: String,
+    pub campaign_id: String,
+    pub ad_group_id: Option<String>,
+    pub ad_id: Option<String>,
+    pub keyword_id: Option<String>,
+    pub keyword_text: Option<String>,
+    pub match_type: Option<String>,
+    pub placement: Option<String>,
+    // 该小时的开始时间
+    pub time_window_start: DateTime<FixedOffset>,
+    #[serde(default)]
+    pub clicks: i64,
+    #[serde(default)]
+    pub impressions: i64,
+    #[serde(default)]
+    pub cost: f64,
+}
+
+// endregion
+
+// region 转化 sp-conversion
+#[derive(Deserialize, Debug, Clone)]
+pub struct SPConversionMessage {
+    pub idempotency_id: String,
+    pub dataset_id: String,
+    pub marketplace_id: String,
+    pub currency: String,
+    pub advertiser_id: String,
+    pub campaign_id: String,
+    pub ad_group_id: Option<String>,
+    pub ad_id: Option<String>,
+    pub keyword_id: Option<String>,
+    pub placement: Option<String>,
+    pub time_window_start: DateTime<FixedOffset>,
+
+    #[serde(default)]
+    pub attributed_conversions_1d: i64,
+    #[serde(default)]
+    pub attributed_conversions_7d: i64,
+    #[serde(default)]
+    pub attributed_conversions_14d: i64,
+    #[serde(default)]
+    pub attributed_conversions_30d: i64,
+    #[serde(default)]
+    pub attributed_conversions_1d_same_sku: i64,
+    #[serde(default)]
+    pub attributed_conversions_7d_same_sku: i64,
+    #[serde(default)]
+    pub attributed_conversions_14d_same_sku: i64,
+    #[serde(default)]
+    pub attributed_conversions_30d_same_sku: i64,
+
+    #[serde(default)]
+    pub attributed_units_ordered_1d: i64,
+    #[serde(default)]
+    pub attributed_units_ordered_7d: i64,
+    #[serde(default)]
+    pub attributed_units_ordered_14d: i64,
+    #[serde(default)]
+    pub attributed_units_ordered_30d: i64,
+
+    #[serde(default)]
+    pub attributed_sales_1d: f64,
+    #[serde(default)]
+    pub attributed_sales_7d: f64,
+    #[serde(default)]
+    pub attributed_sales_14d: f64,
+    #[serde(default)]
+    pub attributed_sales_30d: f64,
+    #[serde(default)]
+    pub attributed_sales_1d_same_sku: f64,
+    #[serde(default)]
+    pub attributed_sales_7d_same_sku: f64,
+    #[serde(default)]
+    pub attributed_sales_14d_same_sku: f64,
+    #[serde(default)]
+    pub attributed_sales_30d_same_sku: f64,
+}
+
+// endregion
+
+// region 转化 sb-conversion, 只有 14 天归因
+#[derive(Deserialize, Debug, Clone)]
+pub struct SBConversionMessage {
+    pub idempotency_id: String,
+    pub dataset_id: String,
+    pub marketplace_id: String,
+    pub currency: String,
+    pub advertiser_id: String,
+    pub campaign_id: String,
+    pub ad_group_id: Option<String>,
+    pub ad_id: Option<String>,
+    pub keyword_id: Option<String>,
+    pub placement: Option<String>,
+    pub time_window_start: DateTime<FixedOffset>,
+
+    #[serde(default)]
+    pub attributed_conversions_14d: i64,
+    #[serde(default)]
+    pub attributed_conversions_14d_same_sku: i64,
+    #[serde(default)]
+    pub attributed_units_ordered_14d: i64,
+    #[serde(default)]
+    pub attributed_sales_14d: f64,
+    #[serde(default)]
+    pub attributed_sales_14d_same_sku: f64,
+    #[serde(default)]
+    pub attributed_detail_page_views_14d: i64,
+    #[serde(default)]
+    pub attributed_branded_searches_14d: i64,
+
+    // 新客(品牌新买家)
+    #[serde(default)]
+    pub attributed_new_to_brand_purchases_14d: i64,
+    #[serde(default)]
+    pub attributed_new_to_brand_units_ordered_14d: i64,
+    #[serde(default)]
+    pub attributed_new_to_brand_sales_14d: f64,
+}
+
+// endregion
+
+// region 转化 sd-conversion, 区分点击归因和浏览归因
+#[derive(Deserialize, Debug, Clone)]
+pub struct SDConversionMessage {
+    pub idempotency_id: String,
+    pub dataset_id: String,
+    pub marketplace_id: String,
+    pub currency: String,
+    pub advertiser_id: String,
+    pub campaign_id: String,
+    pub ad_group_id: Option<String>,
+    pub ad_id: Option<String>,
+    pub target_id: Option<String>,
+    // CPC / VCPM
+    pub cost_type: Option<String>,
+    pub time_window_start: DateTime<FixedOffset>,
+
+    #[serde(default)]
+    pub attributed_conversions_14d: i64,
+    #[serde(default)]
+    pub attributed_conversions_14d_same_sku: i64,
+    #[serde(default)]
+    pub attributed_units_ordered_14d: i64,
+    #[serde(default)]
+    pub attributed_sales_14d: f64,
+    #[serde(default)]
+    pub attributed_sales_14d_same_sku: f64,
+    #[serde(default)]
+    pub attributed_detail_page_view_14d: i64,
+
+    #[serde(default)]
+    pub attributed_new_to_brand_purchases_14d: i64,
+    #[serde(default)]
+    pub attributed_new_to_brand_units_ordered_14d: i64,
+    #[serde(default)]
+    pub attributed_new_to_brand_sales_14d: f64,
+
+    // 浏览归因
+    #[serde(default)]
+    pub view_attributed_conversions_14d: i64,
+    #[serde(default)]
+    pub view_attributed_units_ordered_14d: i64,
+    #[serde(default)]
+    pub view_attributed_sales_14d: f64,
+    #[serde(default)]
+    pub view_attributed_detail_page_view_14d: i64,
+}
+
+// endregion
+
+// region 预算使用 budget-usage
+#[derive(Deserialize, Debug, Clone)]
+pub struct BudgetUsageMessage {
+    pub advertiser_id: String,
+    pub marketplace_id: String,
+    pub dataset_id: String,
+    // 广告活动或广告组合的 id
+    pub budget_scope_id: String,
+    // CAMPAIGN / PORTFOLIO
+    pub budget_scope_type: String,
+    pub advertising_product_type: String,
+    pub budget: f64,
+    pub budget_usage_percentage: f64,
+    pub usage_updated_timestamp: DateTime<Utc>,
+}
+
+// endregion
+
+// region 预算推荐 sp-budget-recommendations
+#[derive(Deserialize, Debug, Clone)]
+pub struct SPBudgetRecommendationMessage {
+    pub dataset_id: String,
+    pub advertiser_id: String,
+    pub marketplace_id: String,
+    pub campaign_id: String,
+    pub suggested_budget: Option<f64>,
+    // 近 7 天因预算不足错失的机会
+    pub seven_days_missed_opportunities: Option<BudgetMissedOpportunities>,
+    pub budget_rule_id: Option<String>,
+    pub rule_name: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct BudgetMissedOpportunities {
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub percent_time_in_budget: Option<f64>,
+    pub estimated_missed_impressions_lower: Option<i64>,
+    pub estimated_missed_impressions_upper: Option<i64>,
+    pub estimated_missed_clicks_lower: Option<i64>,
+    pub estimated_missed_clicks_upper: Option<i64>,
+    pub estimated_missed_sales_lower: Option<f64>,
+    pub estimated_missed_sales_upper: Option<f64>,
+}
+
+// endregion
+
+// region 实体变更 sp-campaigns / sp-adgroups / sp-ads / sp-targets
+#[derive(Deserialize, Debug, Clone)]
+pub struct SPCampaignMessage {
+    pub dataset_id: String,
+    pub advertiser_id: String,
+    pub marketplace_id: String,
+    pub campaign_id: String,
+    pub portfolio_id: Option<String>,
+    pub ad_product: Option<String>,
+    pub name: Option<String>,
+    pub state: Option<String>,
+    pub start_date_time: Option<DateTime<Utc>>,
+    pub end_date_time: Option<DateTime<Utc>>,
+    pub budget: Option<Value>,
+    pub bidding: Option<Value>,
+    pub targeting_settings: Option<String>,
+    pub audit_creation_date_time: Option<DateTime<Utc>>,
+    pub audit_last_updated_date_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SPAdGroupMessage {
+    pub dataset_id: String,
+    pub advertiser_id: String,
+    pub marketplace_id: String,
+    pub ad_group_id: String,
+    pub campaign_id: String,
+    pub ad_product: Option<String>,
+    pub name: Option<String>,
+    pub state: Option<String>,
+    pub bid: Option<Value>,
+    pub audit_creation_date_time: Option<DateTime<Utc>>,
+    pub audit_last_updated_date_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SPAdMessage {
+    pub dataset_id: String,
+    pub advertiser_id: String,
+    pub marketplace_id: String,
+    pub ad_id: String,
+    pub ad_group_id: String,
+    pub campaign_id: String,
+    pub ad_product: Option<String>,
+    pub state: Option<String>,
+    pub creative: Option<Value>,
+    pub audit_creation_date_time: Option<DateTime<Utc>>,
+    pub audit_last_updated_date_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SPTargetMessage {
+    pub dataset_id: String,
+    pub advertiser_id: String,
+    pub marketplace_id: String,
+    pub target_id: String,
+    pub ad_group_id: Option<String>,
+    pub campaign_id: String,
+    pub ad_product: Option<String>,
+    pub state: Option<String>,
+    // KEYWORD / PRODUCT / PRODUCT_CATEGORY / AUTO 等
+    pub target_type: Option<String>,
+    #[serde(default)]
+    pub negative: bool,
+    pub target_details: Option<Value>,
+    pub bid: Option<Value>,
+    pub audit_creation_date_time: Option<DateTime<Utc>>,
+    pub audit_last_updated_date_time: Option<DateTime<Utc>>,
+}
+
+// endregion
@@ -0,0 +1,317 @@
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::{bon, Builder};
+use chrono::{DateTime, Utc};
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+use strum::{AsRefStr, Display, EnumString};
+
+pub const STREAM_SUBSCRIPTION_MEDIA_TYPE: &str =
+    "application/vnd.MarketingStreamSubscriptions.StreamSubscriptionResource.v1.0+json";
+
+// region CreateSubscription
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct CreateSubscription {
+    ads_client: Arc<AdsClient>,
+    data_set_id: StreamDataset,
+    destination: StreamDestination,
+    // 幂等 token, 重复提交不会创建多个订阅
+    client_request_token: String,
+    notes: Option<String>,
+}
+
+impl CreateSubscription {
+    pub async fn fetch(self) -> Result<CreateSubscriptionResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateSubscription {
+    type Response = CreateSubscriptionResponse;
+
+    fn path(&self) -> String {
+        "/streams/subscriptions".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(STREAM_SUBSCRIPTION_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(STREAM_SUBSCRIPTION_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        let mut json_body = json!({
+            "dataSetId": self.data_set_id,
+            "clientRequestToken": self.client_request_token,
+        });
+        match &self.destination {
+            StreamDestination::Sqs { queue_arn } => {
+                json_body["destinationArn"] = json!(queue_arn);
+            }
+            StreamDestination::Firehose {
+                delivery_stream_arn,
+                subscriber_role_arn,
+            } => {
+                json_body["destination"] = json!({
+                    "firehoseDestination": {
+                        "deliveryStreamArn": delivery_stream_arn,
+                        "subscriberRoleArn": subscriber_role_arn,
+                    }
+                });
+            }
+        }
+        if let Some(notes) = &self.notes {
+            json_body["notes"] = json!(notes);
+        }
+        Ok(Some(json_body))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSubscriptionResponse {
+    pub subscription_id: String,
+    pub client_request_token: Option<String>,
+}
+
+// endregion
+
+// region ListSubscriptions
+#[derive(Builder)]
+pub struct ListSubscriptions {
+    ads_client: Arc<AdsClient>,
+    #[builder(default = 100)]
+    max_results: i32,
+}
+
+#[bon]
+impl ListSubscriptions {
+    #[builder]
+    pub async fn fetch(&self, next_token: Option<&str>) -> Result<ListSubscriptionsResponse> {
+        let query = next_token
+            .map(|next_token| vec![("startingToken", next_token.to_string())])
+            .unwrap_or_default();
+        self.ads_client.execute(&self.with_query(query)).await
+    }
+}
+
+impl AdsOperation for ListSubscriptions {
+    type Response = ListSubscriptionsResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/streams/subscriptions".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![("maxResults", self.max_results.to_string())]
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(STREAM_SUBSCRIPTION_MEDIA_TYPE)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListSubscriptionsResponse {
+    pub next_token: Option<String>,
+    #[serde(default)]
+    pub subscriptions: Vec<StreamSubscription>,
+}
+
+// endregion
+
+// region GetSubscription
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct GetSubscription {
+    ads_client: Arc<AdsClient>,
+    subscription_id: String,
+}
+
+impl GetSubscription {
+    pub async fn fetch(&self) -> Result<StreamSubscription> {
+        let data = self.ads_client.execute(self).await?;
+        Ok(data.subscription)
+    }
+}
+
+impl AdsOperation for GetSubscription {
+    type Response = GetSubscriptionResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        format!("/streams/subscriptions/{}", self.subscription_id)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(STREAM_SUBSCRIPTION_MEDIA_TYPE)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSubscriptionResponse {
+    pub subscription: StreamSubscription,
+}
+
+// endregion
+
+// region UpdateSubscription
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct UpdateSubscription {
+    ads_client: Arc<AdsClient>,
+    subscription_id: String,
+    update: StreamSubscriptionUpdate,
+}
+
+impl UpdateSubscription {
+    pub async fn fetch(self) -> Result<()> {
+        self.ads_client.execute(&self).await?;
+        Ok(())
+    }
+}
+
+impl AdsOperation for UpdateSubscription {
+    type Response = IgnoredAny;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        format!("/streams/subscriptions/{}", self.subscription_id)
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(STREAM_SUBSCRIPTION_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(STREAM_SUBSCRIPTION_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.update)?))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct StreamSubscriptionUpdate {
+    // 只能改为 ARCHIVED 或 ACTIVE
+    pub status: Option<StreamSubscriptionStatus>,
+    pub notes: Option<String>,
+}
+
+// endregion
+
+// region 基本数据
+#[derive(Debug, Clone)]
+pub enum StreamDestination {
+    Sqs {
+        queue_arn: String,
+    },
+    Firehose {
+        delivery_stream_arn: String,
+        subscriber_role_arn: String,
+    },
+}
+
+impl StreamDestination {
+    pub fn sqs(queue_arn: &str) -> Self {
+        StreamDestination::Sqs {
+            queue_arn: queue_arn.to_string(),
+        }
+    }
+
+    pub fn firehose(delivery_stream_arn: &str, subscriber_role_arn: &str) -> Self {
+        StreamDestination::Firehose {
+            delivery_stream_arn: delivery_stream_arn.to_string(),
+            subscriber_role_arn: subscriber_role_arn.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamSubscription {
+    pub subscription_id: String,
+    pub data_set_id: StreamDataset,
+    // SQS 订阅
+    pub destination_arn: Option<String>,
+    // Firehose 订阅
+    pub destination: Option<StreamSubscriptionDestination>,
+    pub status: StreamSubscriptionStatus,
+    pub created_date: Option<DateTime<Utc>>,
+    pub updated_date: Option<DateTime<Utc>>,
+    pub notes: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamSubscriptionDestination {
+    pub firehose_destination: Option<FirehoseDestination>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FirehoseDestination {
+    pub delivery_stream_arn: String,
+    pub subscriber_role_arn: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Display, AsRefStr, EnumString)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum StreamDataset {
+    SpTraffic,
+    SpConversion,
+    SbTraffic,
+    SbConversion,
+    SdTraffic,
+    SdConversion,
+    BudgetUsage,
+    SpBudgetRecommendations,
+    SpCampaigns,
+    SpAdgroups,
+    SpAds,
+    SpTargets,
+    #[serde(untagged)]
+    #[strum(default)]
+    Other(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Display, AsRefStr, EnumString)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum StreamSubscriptionStatus {
+    Provisioning,
+    PendingConfirmation,
+    Active,
+    Archived,
+    FailedConfirmation,
+    Failed,
+    Suspended,
+    #[serde(other)]
+    Other,
+}
+
+// endregion
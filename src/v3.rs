@@ -1,4 +1,11 @@
+pub mod bid_recommendations;
+pub mod budget_rules;
+pub mod campaign_budgets;
+pub mod campaign_optimization;
 pub mod keyword_targets;
+pub mod localization;
+pub mod portfolios;
 pub mod product_ads;
 pub mod product_targeting;
+pub mod recommendations;
 pub mod types;
@@ -0,0 +1,350 @@
+use serde::{Deserialize, Serialize};
+
+use super::types::enums::MatchType;
+use crate::client::AdsClient;
+use crate::operation::AdsOperation;
+use anyhow::Result;
+use bon::Builder;
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct BidRecommendations {
+    ads_client: Arc<AdsClient>,
+    filter: BidRecommendationsFilter,
+}
+
+impl BidRecommendations {
+    pub async fn fetch(self) -> Result<BidRecommendationsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for BidRecommendations {
+    type Response = BidRecommendationsResponse;
+
+    fn path(&self) -> String {
+        "/sp/targets/bid/recommendations".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/vnd.spthemebasedbidrecommendation.v4+json")
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/vnd.spthemebasedbidrecommendation.v4+json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        let filter = serde_json::to_value(&self.filter)?;
+        Ok(Some(filter))
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(untagged)]
+pub enum BidRecommendationsFilter {
+    ForAdGroup(BidRecommendationsFilterForAdGroup),
+    ForASINS(BidRecommendationsFilterForASINS),
+}
+
+impl From<BidRecommendationsFilterForAdGroup> for BidRecommendationsFilter {
+    fn from(filter: BidRecommendationsFilterForAdGroup) -> Self {
+        BidRecommendationsFilter::ForAdGroup(filter)
+    }
+}
+
+impl From<BidRecommendationsFilterForASINS> for BidRecommendationsFilter {
+    fn from(filter: BidRecommendationsFilterForASINS) -> Self {
+        BidRecommendationsFilter::ForASINS(filter)
+    }
+}
+
+// 已有广告组: 竞价策略和广告位调整取广告活动当前设置
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct BidRecommendationsFilterForAdGroup {
+    #[builder(field)]
+    targeting_expressions: Vec<TargetingExpression>,
+    ad_group_id: String,
+    campaign_id: String,
+    include_analysis: Option<bool>,
+    #[builder(default = "BIDS_FOR_EXISTING_AD_GROUP".to_string())]
+    recommendation_type: String,
+}
+
+impl<S: bid_recommendations_filter_for_ad_group_builder::State>
+    BidRecommendationsFilterForAdGroupBuilder<S>
+{
+    pub fn expression(mut self, expression: TargetingExpression) -> Self {
+        self.targeting_expressions.push(expression);
+        self
+    }
+
+    pub fn expressions(mut self, expressions: Vec<TargetingExpression>) -> Self {
+        self.targeting_expressions.extend(expressions);
+        self
+    }
+}
+
+// 新 ASIN 组合: 需要自行提供竞价策略和广告位调整
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct BidRecommendationsFilterForASINS {
+    #[builder(field)]
+    targeting_expressions: Vec<TargetingExpression>,
+    #[builder(field)]
+    bidding: BidRecommendationsBidding,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    asins: Vec<String>,
+    include_analysis: Option<bool>,
+    #[builder(default = "BIDS_FOR_NEW_AD_GROUP".to_string())]
+    recommendation_type: String,
+}
+
+impl<S: bid_recommendations_filter_for_a_s_i_n_s_builder::State>
+    BidRecommendationsFilterForASINSBuilder<S>
+{
+    pub fn expression(mut self, expression: TargetingExpression) -> Self {
+        self.targeting_expressions.push(expression);
+        self
+    }
+
+    pub fn expressions(mut self, expressions: Vec<TargetingExpression>) -> Self {
+        self.targeting_expressions.extend(expressions);
+        self
+    }
+
+    pub fn strategy(mut self, strategy: BiddingStrategy) -> Self {
+        self.bidding.strategy = strategy;
+        self
+    }
+
+    pub fn placement_adjustment(mut self, placement: PlacementPredicate, percentage: i32) -> Self {
+        self.bidding.adjustments.push(PlacementAdjustment {
+            percentage,
+            predicate: placement,
+        });
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BidRecommendationsBidding {
+    pub adjustments: Vec<PlacementAdjustment>,
+    pub strategy: BiddingStrategy,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlacementAdjustment {
+    pub percentage: i32,
+    pub predicate: PlacementPredicate,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BiddingStrategy {
+    #[default]
+    LegacyForSales,
+    AutoForSales,
+    Manual,
+    RuleBased,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PlacementPredicate {
+    PlacementTop,
+    PlacementRestOfSearch,
+    PlacementProductPage,
+}
+
+// --- 定向表达式 -------------------
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetingExpression {
+    #[serde(rename = "type")]
+    pub expression_type: TargetingExpressionType,
+    pub value: Option<String>,
+}
+
+impl TargetingExpression {
+    pub fn keyword(keyword: &str, match_type: MatchType) -> Self {
+        let expression_type = match match_type {
+            MatchType::Broad => TargetingExpressionType::KeywordBroadMatch,
+            MatchType::Exact => TargetingExpressionType::KeywordExactMatch,
+            MatchType::Phrase => TargetingExpressionType::KeywordPhraseMatch,
+        };
+        Self {
+            expression_type,
+            value: Some(keyword.to_string()),
+        }
+    }
+
+    pub fn asin(asin: &str) -> Self {
+        Self {
+            expression_type: TargetingExpressionType::PatAsin,
+            value: Some(asin.to_string()),
+        }
+    }
+
+    pub fn category(category_id: &str) -> Self {
+        Self {
+            expression_type: TargetingExpressionType::PatCategory,
+            value: Some(category_id.to_string()),
+        }
+    }
+
+    /// 自动投放的四种匹配方式: 紧密/宽泛/同类/关联
+    pub fn auto_targets() -> Vec<Self> {
+        [
+            TargetingExpressionType::CloseMatch,
+            TargetingExpressionType::LooseMatch,
+            TargetingExpressionType::Substitutes,
+            TargetingExpressionType::Complements,
+        ]
+        .into_iter()
+        .map(|expression_type| Self {
+            expression_type,
+            value: None,
+        })
+        .collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TargetingExpressionType {
+    CloseMatch,
+    LooseMatch,
+    Substitutes,
+    Complements,
+    KeywordBroadMatch,
+    KeywordExactMatch,
+    KeywordPhraseMatch,
+    PatAsin,
+    PatCategory,
+    PatAsinExpanded,
+    PatCategoryExpanded,
+    // 响应中出现的未知类型, 不要用于请求
+    #[serde(other)]
+    Other,
+}
+
+// --- 响应结构 -------------------
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BidRecommendationsResponse {
+    pub bid_recommendations: Vec<ThemeBasedBidRecommendation>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeBasedBidRecommendation {
+    pub bid_recommendations_for_targeting_expressions: Vec<BidRecommendationPerTarget>,
+    pub impact_metrics: Option<ImpactMetrics>,
+    pub theme: BidRecommendationTheme,
+}
+
+impl ThemeBasedBidRecommendation {
+    /// 查找某个定向表达式的建议竞价
+    pub fn find(&self, expression: &TargetingExpression) -> Option<&BidRecommendationPerTarget> {
+        self.bid_recommendations_for_targeting_expressions
+            .iter()
+            .find(|item| &item.targeting_expression == expression)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BidRecommendationTheme {
+    ConversionOpportunities, // 转化机会
+    PrimeDay,                // Prime Day 大促
+    PrimeBigDealDays,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BidRecommendationPerTarget {
+    // 一般为低/中/高三档建议竞价
+    pub bid_values: Vec<BidValue>,
+    pub targeting_expression: TargetingExpression,
+}
+
+impl BidRecommendationPerTarget {
+    /// 中间档建议竞价
+    pub fn suggested_bid(&self) -> Option<f64> {
+        let len = self.bid_values.len();
+        if len == 0 {
+            return None;
+        }
+        Some(self.bid_values[len / 2].suggested_bid)
+    }
+
+    /// 按广告位调整比例计算各广告位的实际建议竞价, 未设置调整的广告位按基础竞价计算
+    pub fn placement_bids(&self, adjustments: &[PlacementAdjustment]) -> Vec<PlacementBid> {
+        let Some(base) = self.suggested_bid() else {
+            return vec![];
+        };
+        [
+            PlacementPredicate::PlacementTop,
+            PlacementPredicate::PlacementRestOfSearch,
+            PlacementPredicate::PlacementProductPage,
+        ]
+        .into_iter()
+        .map(|placement| {
+            let percentage = adjustments
+                .iter()
+                .find(|item| item.predicate == placement)
+                .map(|item| item.percentage)
+                .unwrap_or(0);
+            PlacementBid {
+                placement,
+                bid: base * (100 + percentage) as f64 / 100.0,
+            }
+        })
+        .collect()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BidValue {
+    pub suggested_bid: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlacementBid {
+    pub placement: PlacementPredicate,
+    pub bid: f64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImpactMetrics {
+    pub clicks: Option<ImpactMetricValues>,
+    pub orders: Option<ImpactMetricValues>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImpactMetricValues {
+    pub values: Vec<ImpactMetricRange>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImpactMetricRange {
+    pub lower: Option<f64>,
+    pub upper: Option<f64>,
+}
@@ -0,0 +1,668 @@
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::{bon, Builder};
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+
+// ==============================================================================
+// 创建/更新/查询预算规则
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct CreateBudgetRules {
+    ads_client: Arc<AdsClient>,
+    rules: Vec<BudgetRuleDetails>,
+}
+
+impl CreateBudgetRules {
+    pub async fn fetch(self) -> Result<BudgetRulesOperationResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateBudgetRules {
+    type Response = BudgetRulesOperationResponse;
+
+    fn path(&self) -> String {
+        "/sp/budgetRules".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "budgetRulesDetails": self.rules,
+        })))
+    }
+}
+
+#[derive(Builder)]
+pub struct UpdateBudgetRules {
+    ads_client: Arc<AdsClient>,
+    rules: Vec<BudgetRuleUpdate>,
+}
+
+impl UpdateBudgetRules {
+    pub async fn fetch(self) -> Result<BudgetRulesOperationResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateBudgetRules {
+    type Response = BudgetRulesOperationResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sp/budgetRules".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "budgetRulesDetails": self.rules,
+        })))
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRuleUpdate {
+    pub rule_details: BudgetRuleDetails,
+    pub rule_id: String,
+}
+
+impl BudgetRuleUpdate {
+    pub fn new(rule_id: &str, rule_details: BudgetRuleDetails) -> Self {
+        Self {
+            rule_details,
+            rule_id: rule_id.to_string(),
+        }
+    }
+}
+
+#[derive(Builder)]
+pub struct ListBudgetRules {
+    ads_client: Arc<AdsClient>,
+    // 每页数量, 最大 30
+    #[builder(default = 30)]
+    page_size: i32,
+}
+
+#[bon]
+impl ListBudgetRules {
+    #[builder]
+    pub async fn fetch(&self, next_token: Option<&str>) -> Result<ListBudgetRulesResponse> {
+        let query = next_token
+            .map(|next_token| vec![("nextToken", next_token.to_string())])
+            .unwrap_or_default();
+        self.ads_client.execute(&self.with_query(query)).await
+    }
+}
+
+impl AdsOperation for ListBudgetRules {
+    type Response = ListBudgetRulesResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/sp/budgetRules".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![("pageSize", self.page_size.to_string())]
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListBudgetRulesResponse {
+    #[serde(default)]
+    pub budget_rules_for_advertiser_response: Vec<BudgetRule>,
+    pub next_token: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct GetBudgetRule {
+    ads_client: Arc<AdsClient>,
+    rule_id: String,
+}
+
+impl GetBudgetRule {
+    pub async fn fetch(self) -> Result<BudgetRule> {
+        let data = self.ads_client.execute(&self).await?;
+        Ok(data.budget_rule)
+    }
+}
+
+impl AdsOperation for GetBudgetRule {
+    type Response = GetBudgetRuleResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        format!("/sp/budgetRules/{}", self.rule_id)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBudgetRuleResponse {
+    pub budget_rule: BudgetRule,
+}
+
+// --- 创建/更新的响应结构 -------------------
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRulesOperationResponse {
+    #[serde(default)]
+    pub responses: Vec<BudgetRuleResponseItem>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRuleResponseItem {
+    pub code: String,
+    pub details: Option<String>,
+    pub rule_id: Option<String>,
+}
+
+impl BudgetRuleResponseItem {
+    pub fn is_success(&self) -> bool {
+        self.code == "SUCCESS"
+    }
+}
+
+// ==============================================================================
+// 预算规则与广告活动的关联
+// ==============================================================================
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct AssociateBudgetRules {
+    ads_client: Arc<AdsClient>,
+    campaign_id: String,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    rule_ids: Vec<String>,
+}
+
+impl AssociateBudgetRules {
+    pub async fn fetch(self) -> Result<BudgetRulesOperationResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for AssociateBudgetRules {
+    type Response = BudgetRulesOperationResponse;
+
+    fn path(&self) -> String {
+        format!("/sp/campaigns/{}/budgetRules", self.campaign_id)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "budgetRuleIds": self.rule_ids,
+        })))
+    }
+}
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct DisassociateBudgetRule {
+    ads_client: Arc<AdsClient>,
+    campaign_id: String,
+    rule_id: String,
+}
+
+impl DisassociateBudgetRule {
+    pub async fn fetch(self) -> Result<()> {
+        self.ads_client.execute(&self).await?;
+        Ok(())
+    }
+}
+
+impl AdsOperation for DisassociateBudgetRule {
+    type Response = IgnoredAny;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Delete
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/sp/campaigns/{}/budgetRules/{}",
+            self.campaign_id, self.rule_id
+        )
+    }
+}
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListCampaignBudgetRules {
+    ads_client: Arc<AdsClient>,
+    campaign_id: String,
+}
+
+impl ListCampaignBudgetRules {
+    pub async fn fetch(self) -> Result<Vec<BudgetRule>> {
+        let data = self.ads_client.execute(&self).await?;
+        Ok(data.associated_rules)
+    }
+}
+
+impl AdsOperation for ListCampaignBudgetRules {
+    type Response = ListCampaignBudgetRulesResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        format!("/sp/campaigns/{}/budgetRules", self.campaign_id)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListCampaignBudgetRulesResponse {
+    #[serde(default)]
+    pub associated_rules: Vec<BudgetRule>,
+}
+
+// ==============================================================================
+// 规则执行历史
+// ==============================================================================
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct BudgetRuleHistory {
+    ads_client: Arc<AdsClient>,
+    campaign_id: String,
+    // 格式 YYYYMMDD
+    start_date: String,
+    end_date: String,
+    #[builder(default = 30)]
+    page_size: i32,
+}
+
+#[bon]
+impl BudgetRuleHistory {
+    #[builder]
+    pub async fn fetch(&self, next_token: Option<&str>) -> Result<BudgetRuleHistoryResponse> {
+        let query = next_token
+            .map(|next_token| vec![("nextToken", next_token.to_string())])
+            .unwrap_or_default();
+        self.ads_client.execute(&self.with_query(query)).await
+    }
+}
+
+impl AdsOperation for BudgetRuleHistory {
+    type Response = BudgetRuleHistoryResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/sp/campaigns/{}/budgetRules/budgetHistory",
+            self.campaign_id
+        )
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("startDate", self.start_date.clone()),
+            ("endDate", self.end_date.clone()),
+            ("pageSize", self.page_size.to_string()),
+        ]
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRuleHistoryResponse {
+    #[serde(default)]
+    pub budget_rules_history: Vec<BudgetRuleEvaluation>,
+    pub next_token: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRuleEvaluation {
+    // 规则生效后的预算
+    pub applied_budget: Option<f64>,
+    pub budget_increase_by: Option<BudgetIncreaseBy>,
+    // 规则生效前的原始预算
+    pub daily_budget: Option<f64>,
+    // 执行时间, epoch 毫秒
+    pub executed_at: Option<i64>,
+    pub performance_measure_condition: Option<PerformanceMeasureCondition>,
+    pub rule_id: String,
+    pub rule_name: Option<String>,
+    pub rule_type: Option<BudgetRuleType>,
+    pub status: Option<String>,
+}
+
+// ==============================================================================
+// 大促事件的预算规则推荐
+// ==============================================================================
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct BudgetRuleRecommendations {
+    ads_client: Arc<AdsClient>,
+    campaign_id: String,
+}
+
+impl BudgetRuleRecommendations {
+    pub async fn fetch(self) -> Result<Vec<BudgetRuleEventRecommendation>> {
+        let data = self.ads_client.execute(&self).await?;
+        Ok(data.recommended_budget_rule_events)
+    }
+}
+
+impl AdsOperation for BudgetRuleRecommendations {
+    type Response = BudgetRuleRecommendationsResponse;
+
+    fn path(&self) -> String {
+        "/sp/campaigns/budgetRules/recommendations".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/vnd.spbudgetrulesrecommendation.v3+json")
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/vnd.spbudgetrulesrecommendation.v3+json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "campaignId": self.campaign_id,
+        })))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRuleRecommendationsResponse {
+    #[serde(default)]
+    pub recommended_budget_rule_events: Vec<BudgetRuleEventRecommendation>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRuleEventRecommendation {
+    pub end_date: Option<String>,
+    pub event_id: String,
+    pub event_name: String,
+    pub start_date: Option<String>,
+    // 建议的预算提升百分比
+    pub suggested_budget_increase_percent: Option<f64>,
+}
+
+impl BudgetRuleEventRecommendation {
+    /// 直接按推荐生成一条事件型规则, 推荐没有给出提升百分比时返回 None
+    pub fn to_rule_details(&self, name: &str) -> Option<BudgetRuleDetails> {
+        let percent = self.suggested_budget_increase_percent?;
+        Some(self.to_rule_details_with_percent(name, percent))
+    }
+
+    /// 按推荐的事件生成规则, 提升百分比由调用方指定
+    pub fn to_rule_details_with_percent(&self, name: &str, percent: f64) -> BudgetRuleDetails {
+        BudgetRuleDetails::builder(name)
+            .schedule_event(&self.event_id)
+            .budget_increase_by(BudgetIncreaseBy::percent(percent))
+            .build()
+    }
+}
+
+// ==============================================================================
+// 基本数据
+// ==============================================================================
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRule {
+    pub created_date: Option<i64>,
+    pub last_updated_date: Option<i64>,
+    pub rule_details: BudgetRuleDetails,
+    pub rule_id: String,
+    pub rule_state: Option<String>,
+    pub rule_status: Option<BudgetRuleStatus>,
+}
+
+// 规则内容, 读写共用. SCHEDULE 规则按日期范围/事件生效, PERFORMANCE 规则按指标阈值生效
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRuleDetails {
+    #[builder(start_fn)]
+    pub name: String,
+
+    #[builder(field)]
+    pub duration: Option<BudgetRuleDuration>,
+
+    #[builder(field)]
+    pub performance_measure_condition: Option<PerformanceMeasureCondition>,
+
+    #[builder(field = BudgetRuleType::Schedule)]
+    pub rule_type: BudgetRuleType,
+
+    pub budget_increase_by: BudgetIncreaseBy,
+    pub recurrence: Option<BudgetRuleRecurrence>,
+}
+
+impl<S: budget_rule_details_builder::State> BudgetRuleDetailsBuilder<S> {
+    /// 在固定日期范围内生效 (格式 YYYYMMDD, end_date 可为空)
+    pub fn schedule_date_range(mut self, start_date: &str, end_date: Option<&str>) -> Self {
+        self.duration = Some(BudgetRuleDuration {
+            date_range_type_rule_duration: Some(DateRangeTypeRuleDuration {
+                end_date: end_date.map(|item| item.to_string()),
+                start_date: start_date.to_string(),
+            }),
+            event_type_rule_duration: None,
+        });
+        self
+    }
+
+    /// 在大促等事件期间生效, event_id 来自预算规则推荐
+    pub fn schedule_event(mut self, event_id: &str) -> Self {
+        self.duration = Some(BudgetRuleDuration {
+            date_range_type_rule_duration: None,
+            event_type_rule_duration: Some(EventTypeRuleDuration {
+                end_date: None,
+                event_id: event_id.to_string(),
+                event_name: None,
+                start_date: None,
+            }),
+        });
+        self
+    }
+
+    /// 按 ACOS/CTR/CVR/ROAS 阈值触发的表现型规则
+    pub fn performance(
+        mut self,
+        metric_name: PerformanceMetric,
+        comparison_operator: ComparisonOperator,
+        threshold: f64,
+    ) -> Self {
+        self.rule_type = BudgetRuleType::Performance;
+        self.performance_measure_condition = Some(PerformanceMeasureCondition {
+            comparison_operator,
+            metric_name,
+            threshold,
+        });
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRuleDuration {
+    pub date_range_type_rule_duration: Option<DateRangeTypeRuleDuration>,
+    pub event_type_rule_duration: Option<EventTypeRuleDuration>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DateRangeTypeRuleDuration {
+    pub end_date: Option<String>,
+    pub start_date: String,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EventTypeRuleDuration {
+    pub end_date: Option<String>,
+    pub event_id: String,
+    pub event_name: Option<String>,
+    pub start_date: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRuleRecurrence {
+    pub days_of_week: Option<Vec<DayOfWeek>>,
+    pub intra_day_schedule: Option<Vec<IntraDaySchedule>>,
+    #[serde(rename = "type")]
+    pub recurrence_type: RecurrenceType,
+}
+
+impl BudgetRuleRecurrence {
+    pub fn daily() -> Self {
+        Self {
+            days_of_week: None,
+            intra_day_schedule: None,
+            recurrence_type: RecurrenceType::Daily,
+        }
+    }
+
+    pub fn weekly(days_of_week: Vec<DayOfWeek>) -> Self {
+        Self {
+            days_of_week: Some(days_of_week),
+            intra_day_schedule: None,
+            recurrence_type: RecurrenceType::Weekly,
+        }
+    }
+
+    /// 限定每天生效的时间段, 格式 HH:MM
+    pub fn between(mut self, start_time: &str, end_time: &str) -> Self {
+        self.intra_day_schedule
+            .get_or_insert_with(Vec::new)
+            .push(IntraDaySchedule {
+                end_time: end_time.to_string(),
+                start_time: start_time.to_string(),
+            });
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IntraDaySchedule {
+    pub end_time: String,
+    pub start_time: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetIncreaseBy {
+    #[serde(rename = "type")]
+    pub increase_type: BudgetIncreaseType,
+    pub value: f64,
+}
+
+impl BudgetIncreaseBy {
+    pub fn percent(value: f64) -> Self {
+        Self {
+            increase_type: BudgetIncreaseType::Percent,
+            value,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceMeasureCondition {
+    pub comparison_operator: ComparisonOperator,
+    pub metric_name: PerformanceMetric,
+    pub threshold: f64,
+}
+
+// --- 枚举 -------------------
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BudgetRuleType {
+    Schedule,
+    Performance,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BudgetRuleStatus {
+    Active,
+    Expired,
+    Pending,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecurrenceType {
+    Daily,
+    Weekly,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DayOfWeek {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BudgetIncreaseType {
+    Percent,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PerformanceMetric {
+    Acos,
+    Ctr,
+    Cvr,
+    Roas,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ComparisonOperator {
+    GreaterThan,
+    LessThan,
+    EqualTo,
+    LessThanOrEqualTo,
+    GreaterThanOrEqualTo,
+}
@@ -0,0 +1,171 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::AdsOperation;
+use anyhow::Result;
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+// ==============================================================================
+// 广告活动预算使用率
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct CampaignBudgetUsage {
+    ads_client: Arc<AdsClient>,
+    // 单次最多 100 个
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    campaign_ids: Vec<String>,
+}
+
+impl CampaignBudgetUsage {
+    pub async fn fetch(self) -> Result<CampaignBudgetUsageResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CampaignBudgetUsage {
+    type Response = CampaignBudgetUsageResponse;
+
+    fn path(&self) -> String {
+        "/sp/campaigns/budget/usage".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/vnd.spcampaignbudgetusage.v1+json")
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/vnd.spcampaignbudgetusage.v1+json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "campaignIds": self.campaign_ids,
+        })))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignBudgetUsageResponse {
+    #[serde(default)]
+    pub error: Vec<CampaignBudgetError>,
+    #[serde(default)]
+    pub success: Vec<CampaignBudgetUsageItem>,
+}
+
+impl CampaignBudgetUsageResponse {
+    /// 使用率达到阈值(百分比)的广告活动, 用于提示即将超预算
+    pub fn running_out(&self, threshold_percent: f64) -> Vec<&CampaignBudgetUsageItem> {
+        self.success
+            .iter()
+            .filter(|item| item.budget_usage_percent >= threshold_percent)
+            .collect()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignBudgetUsageItem {
+    pub budget: f64,
+    // 当前已花费占预算的百分比
+    pub budget_usage_percent: f64,
+    pub campaign_id: String,
+    pub index: i64,
+    pub usage_updated_timestamp: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignBudgetError {
+    pub campaign_id: Option<String>,
+    pub code: Option<String>,
+    pub details: Option<String>,
+    pub index: i64,
+}
+
+// ==============================================================================
+// 预算推荐
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct CampaignBudgetRecommendations {
+    ads_client: Arc<AdsClient>,
+    // 单次最多 100 个
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    campaign_ids: Vec<String>,
+}
+
+impl CampaignBudgetRecommendations {
+    pub async fn fetch(self) -> Result<CampaignBudgetRecommendationsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CampaignBudgetRecommendations {
+    type Response = CampaignBudgetRecommendationsResponse;
+
+    fn path(&self) -> String {
+        "/sp/campaigns/budgetRecommendations".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/vnd.budgetrecommendation.v3+json")
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/vnd.budgetrecommendation.v3+json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "campaignIds": self.campaign_ids,
+        })))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignBudgetRecommendationsResponse {
+    #[serde(default)]
+    pub budget_recommendations_error_results: Vec<CampaignBudgetError>,
+    #[serde(default)]
+    pub budget_recommendations_success_results: Vec<CampaignBudgetRecommendation>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignBudgetRecommendation {
+    pub budget_rule_recommendation: Option<BudgetRuleRecommendation>,
+    pub campaign_id: String,
+    pub index: i64,
+    pub seven_days_missed_opportunities: Option<MissedOpportunities>,
+    pub suggested_budget: Option<f64>,
+}
+
+// 过去 7 天因预算不足错过的机会估算
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MissedOpportunities {
+    pub end_date: Option<String>,
+    pub estimated_missed_clicks_lower: Option<i64>,
+    pub estimated_missed_clicks_upper: Option<i64>,
+    pub estimated_missed_impressions_lower: Option<i64>,
+    pub estimated_missed_impressions_upper: Option<i64>,
+    pub estimated_missed_sales_lower: Option<f64>,
+    pub estimated_missed_sales_upper: Option<f64>,
+    // 预算内投放时间占比
+    pub percent_time_in_budget: Option<f64>,
+    pub start_date: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetRuleRecommendation {
+    pub rule_id: Option<String>,
+    pub rule_name: Option<String>,
+    pub suggested_budget_increase_percent: Option<f64>,
+}
@@ -0,0 +1,399 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use anyhow::Result;
+use bon::Builder;
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+
+// ==============================================================================
+// 创建/更新/删除/查询广告活动优化规则 (基于规则的竞价)
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct CreateCampaignOptimizationRule {
+    ads_client: Arc<AdsClient>,
+    rule: CampaignOptimizationRuleCreate,
+}
+
+impl CreateCampaignOptimizationRule {
+    pub async fn fetch(self) -> Result<CampaignOptimizationRuleResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateCampaignOptimizationRule {
+    type Response = CampaignOptimizationRuleResponse;
+
+    fn path(&self) -> String {
+        "/sp/rules/campaignOptimization".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        let json_body = serde_json::to_value(&self.rule)?;
+        Ok(Some(json_body))
+    }
+}
+
+#[derive(Builder)]
+pub struct UpdateCampaignOptimizationRule {
+    ads_client: Arc<AdsClient>,
+    rule: CampaignOptimizationRuleUpdate,
+}
+
+impl UpdateCampaignOptimizationRule {
+    pub async fn fetch(self) -> Result<CampaignOptimizationRuleResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdateCampaignOptimizationRule {
+    type Response = CampaignOptimizationRuleResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/sp/rules/campaignOptimization".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        let json_body = serde_json::to_value(&self.rule)?;
+        Ok(Some(json_body))
+    }
+}
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct DelCampaignOptimizationRule {
+    ads_client: Arc<AdsClient>,
+    campaign_optimization_id: String,
+}
+
+impl DelCampaignOptimizationRule {
+    pub async fn fetch(self) -> Result<CampaignOptimizationRuleResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for DelCampaignOptimizationRule {
+    type Response = CampaignOptimizationRuleResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Delete
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/sp/rules/campaignOptimization/{}",
+            self.campaign_optimization_id
+        )
+    }
+}
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct GetCampaignOptimizationRule {
+    ads_client: Arc<AdsClient>,
+    campaign_optimization_id: String,
+}
+
+impl GetCampaignOptimizationRule {
+    pub async fn fetch(self) -> Result<CampaignOptimizationRule> {
+        let data = self.ads_client.execute(&self).await?;
+        Ok(data.campaign_optimization_rule)
+    }
+}
+
+impl AdsOperation for GetCampaignOptimizationRule {
+    type Response = GetCampaignOptimizationRuleResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/sp/rules/campaignOptimization/{}",
+            self.campaign_optimization_id
+        )
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetCampaignOptimizationRuleResponse {
+    #[serde(
+        rename = "CampaignOptimizationRule",
+        alias = "campaignOptimizationRule"
+    )]
+    pub campaign_optimization_rule: CampaignOptimizationRule,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignOptimizationRuleResponse {
+    pub campaign_optimization_id: Option<String>,
+    pub code: Option<String>,
+    pub details: Option<String>,
+}
+
+// --- 创建/更新的请求体 -------------------
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignOptimizationRuleCreate {
+    #[builder(start_fn)]
+    pub rule_name: String,
+
+    #[builder(field)]
+    pub rule_condition: Vec<CampaignOptimizationRuleCondition>,
+
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    pub campaign_ids: Vec<String>,
+
+    #[builder(default = CampaignOptimizationRecurrence::daily())]
+    pub recurrence: CampaignOptimizationRecurrence,
+
+    #[builder(default = CampaignOptimizationRuleAction::Adopt)]
+    pub rule_action: CampaignOptimizationRuleAction,
+
+    #[builder(default = CampaignOptimizationRuleType::Bid)]
+    pub rule_type: CampaignOptimizationRuleType,
+}
+
+impl<S: campaign_optimization_rule_create_builder::State> CampaignOptimizationRuleCreateBuilder<S> {
+    /// 竞价朝 ROAS 目标调整, 例如 roas_target(3.5) 表示 ROAS >= 3.5
+    pub fn roas_target(mut self, value: f64) -> Self {
+        self.rule_condition
+            .push(CampaignOptimizationRuleCondition::roas_at_least(value));
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignOptimizationRuleUpdate {
+    #[builder(start_fn)]
+    pub campaign_optimization_id: String,
+
+    #[builder(field)]
+    pub rule_condition: Vec<CampaignOptimizationRuleCondition>,
+
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    pub campaign_ids: Vec<String>,
+
+    #[builder(default = CampaignOptimizationRecurrence::daily())]
+    pub recurrence: CampaignOptimizationRecurrence,
+
+    #[builder(default = CampaignOptimizationRuleAction::Adopt)]
+    pub rule_action: CampaignOptimizationRuleAction,
+
+    pub rule_name: Option<String>,
+
+    #[builder(default = CampaignOptimizationRuleType::Bid)]
+    pub rule_type: CampaignOptimizationRuleType,
+}
+
+impl<S: campaign_optimization_rule_update_builder::State> CampaignOptimizationRuleUpdateBuilder<S> {
+    pub fn roas_target(mut self, value: f64) -> Self {
+        self.rule_condition
+            .push(CampaignOptimizationRuleCondition::roas_at_least(value));
+        self
+    }
+}
+
+// ==============================================================================
+// 广告活动的规则状态 / 资格检查
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct CampaignOptimizationRuleState {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    campaign_ids: Vec<String>,
+}
+
+impl CampaignOptimizationRuleState {
+    pub async fn fetch(self) -> Result<Vec<CampaignOptimizationRuleStateItem>> {
+        let data = self.ads_client.execute(&self).await?;
+        Ok(data.responses)
+    }
+}
+
+impl AdsOperation for CampaignOptimizationRuleState {
+    type Response = CampaignOptimizationRuleStateResponse;
+
+    fn path(&self) -> String {
+        "/sp/rules/campaignOptimization/state".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "campaignIds": self.campaign_ids,
+        })))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignOptimizationRuleStateResponse {
+    #[serde(default)]
+    pub responses: Vec<CampaignOptimizationRuleStateItem>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignOptimizationRuleStateItem {
+    pub campaign_id: String,
+    pub campaign_optimization_id: Option<String>,
+    pub code: Option<String>,
+    pub details: Option<String>,
+    pub rule_state: Option<String>,
+}
+
+#[derive(Builder)]
+pub struct CampaignOptimizationEligibility {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    campaign_ids: Vec<String>,
+}
+
+impl CampaignOptimizationEligibility {
+    pub async fn fetch(self) -> Result<Vec<CampaignOptimizationEligibilityItem>> {
+        let data = self.ads_client.execute(&self).await?;
+        Ok(data.responses)
+    }
+}
+
+impl AdsOperation for CampaignOptimizationEligibility {
+    type Response = CampaignOptimizationEligibilityResponse;
+
+    fn path(&self) -> String {
+        "/sp/rules/campaignOptimization/eligibility".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({ "campaignIds": self.campaign_ids })))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignOptimizationEligibilityResponse {
+    #[serde(default)]
+    pub responses: Vec<CampaignOptimizationEligibilityItem>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignOptimizationEligibilityItem {
+    pub campaign_id: String,
+    pub details: Option<String>,
+    pub rule_type: Option<CampaignOptimizationRuleType>,
+    pub status: CampaignOptimizationEligibilityStatus,
+}
+
+// ==============================================================================
+// 基本数据
+// ==============================================================================
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignOptimizationRule {
+    #[serde(default)]
+    pub campaign_ids: Vec<String>,
+    pub campaign_optimization_id: String,
+    pub created_date: Option<i64>,
+    pub recurrence: Option<CampaignOptimizationRecurrence>,
+    pub rule_action: Option<CampaignOptimizationRuleAction>,
+    #[serde(default)]
+    pub rule_condition: Vec<CampaignOptimizationRuleCondition>,
+    pub rule_name: Option<String>,
+    pub rule_status: Option<String>,
+    pub rule_type: Option<CampaignOptimizationRuleType>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignOptimizationRuleCondition {
+    pub comparison_operator: CampaignOptimizationComparisonOperator,
+    pub metric_name: CampaignOptimizationMetric,
+    pub value: f64,
+}
+
+impl CampaignOptimizationRuleCondition {
+    pub fn roas_at_least(value: f64) -> Self {
+        Self {
+            comparison_operator: CampaignOptimizationComparisonOperator::GreaterThanOrEqualTo,
+            metric_name: CampaignOptimizationMetric::Roas,
+            value,
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignOptimizationRecurrence {
+    #[serde(rename = "type")]
+    pub recurrence_type: String,
+}
+
+impl CampaignOptimizationRecurrence {
+    pub fn daily() -> Self {
+        Self {
+            recurrence_type: "DAILY".to_string(),
+        }
+    }
+}
+
+// --- 枚举 -------------------
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CampaignOptimizationRuleAction {
+    Adopt,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CampaignOptimizationRuleType {
+    Bid,
+    Keyword,
+    Product,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CampaignOptimizationMetric {
+    Roas,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CampaignOptimizationComparisonOperator {
+    GreaterThan,
+    GreaterThanOrEqualTo,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CampaignOptimizationEligibilityStatus {
+    Eligible,
+    Ineligible,
+    #[serde(other)]
+    Other,
+}
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use super::types::enums::{Locale, MatchType, SortDimension};
 use crate::client::AdsClient;
+use crate::operation::AdsOperation;
 use anyhow::Result;
 use bon::Builder;
 use serde_with::skip_serializing_none;
@@ -16,19 +18,23 @@ pub struct KeywordRecommendations {
 
 impl KeywordRecommendations {
     pub async fn fetch(self) -> Result<Vec<KeywordRecommendationsResponse>> {
-        let filter = serde_json::to_value(&self.filter)?;
-        let response = self
-            .ads_client
-            .post()
-            .path("/sp/targets/keywords/recommendations")
-            .json_body(filter)
-            .content_type("application/vnd.spkeywordsrecommendation.v3+json")
-            .call()
-            .await?;
-        let data = response
-            .json::<Vec<KeywordRecommendationsResponse>>()
-            .await?;
-        Ok(data)
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for KeywordRecommendations {
+    type Response = Vec<KeywordRecommendationsResponse>;
+
+    fn path(&self) -> String {
+        "/sp/targets/keywords/recommendations".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/vnd.spkeywordsrecommendation.v3+json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
     }
 }
 
@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::AdsOperation;
+use anyhow::Result;
+use bon::Builder;
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::types::enums::Locale;
+
+// ==============================================================================
+// 关键词本地化 (翻译到目标站点语言)
+// ==============================================================================
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct LocalizeKeywords {
+    ads_client: Arc<AdsClient>,
+    // 例如 US
+    source_country_code: String,
+    source_locale: Locale,
+    // 例如 ["DE", "FR", "JP"]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    target_country_codes: Vec<String>,
+    target_locales: Vec<Locale>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    keywords: Vec<String>,
+}
+
+impl LocalizeKeywords {
+    pub async fn fetch(self) -> Result<Vec<LocalizedKeywordResponse>> {
+        let data = self.ads_client.execute(&self).await?;
+        Ok(data.localized_keyword_responses)
+    }
+}
+
+impl AdsOperation for LocalizeKeywords {
+    type Response = LocalizeKeywordsResponse;
+
+    fn path(&self) -> String {
+        "/keywords/localize".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/json")
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        let keywords: Vec<_> = self
+            .keywords
+            .iter()
+            .map(|keyword| json!({ "keyword": keyword }))
+            .collect();
+        let target_locales: Vec<_> = self.target_locales.iter().map(Locale::code).collect();
+        let json_body = json!({
+            "sourceCountryCode": self.source_country_code,
+            "sourceLocale": self.source_locale.code(),
+            "targetCountryCodes": self.target_country_codes,
+            "targetLocales": target_locales,
+            "keywords": keywords,
+        });
+        Ok(Some(json_body))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizeKeywordsResponse {
+    #[serde(default)]
+    pub localized_keyword_responses: Vec<LocalizedKeywordResponse>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedKeywordResponse {
+    pub source_keyword: LocalizedKeyword,
+    // key 为 de_DE 形式的 locale
+    #[serde(default)]
+    pub localized_keywords: HashMap<String, LocalizedKeyword>,
+}
+
+impl LocalizedKeywordResponse {
+    pub fn localized(&self, locale: &Locale) -> Option<&str> {
+        self.localized_keywords
+            .get(&locale.code())
+            .map(|item| item.keyword.as_str())
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedKeyword {
+    pub keyword: String,
+}
+
+// ==============================================================================
+// 商品本地化 (匹配目标站点的 ASIN/SKU)
+// ==============================================================================
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct LocalizeProducts {
+    #[builder(field)]
+    source_products: Vec<LocalizationProduct>,
+    ads_client: Arc<AdsClient>,
+    #[builder(default = "SPONSORED_PRODUCTS".to_string())]
+    ad_type: String,
+    source_country_code: String,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    target_country_codes: Vec<String>,
+    // 卖家必填, 供应商不需要
+    source_advertiser_id: Option<String>,
+    target_advertiser_ids: Option<HashMap<String, String>>,
+}
+
+impl<S: localize_products_builder::State> LocalizeProductsBuilder<S> {
+    pub fn asin(mut self, asin: &str) -> Self {
+        self.source_products.push(LocalizationProduct {
+            asin: Some(asin.to_string()),
+            sku: None,
+        });
+        self
+    }
+
+    pub fn sku(mut self, sku: &str) -> Self {
+        self.source_products.push(LocalizationProduct {
+            asin: None,
+            sku: Some(sku.to_string()),
+        });
+        self
+    }
+}
+
+impl LocalizeProducts {
+    pub async fn fetch(self) -> Result<Vec<LocalizedProductResponse>> {
+        let data = self.ads_client.execute(&self).await?;
+        Ok(data.localized_product_responses)
+    }
+}
+
+impl AdsOperation for LocalizeProducts {
+    type Response = LocalizeProductsResponse;
+
+    fn path(&self) -> String {
+        "/products/localize".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/json")
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        let mut json_body = json!({
+            "adType": self.ad_type,
+            "sourceCountryCode": self.source_country_code,
+            "targetCountryCodes": self.target_country_codes,
+            "sourceProducts": self.source_products,
+        });
+        if let Some(source_advertiser_id) = &self.source_advertiser_id {
+            json_body["sourceAdvertiserId"] = json!(source_advertiser_id);
+        }
+        if let Some(target_advertiser_ids) = &self.target_advertiser_ids {
+            json_body["targetAdvertiserIds"] = json!(target_advertiser_ids);
+        }
+        Ok(Some(json_body))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizationProduct {
+    pub asin: Option<String>,
+    pub sku: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizeProductsResponse {
+    #[serde(default)]
+    pub localized_product_responses: Vec<LocalizedProductResponse>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedProductResponse {
+    pub source_product: LocalizationProduct,
+    // key 为目标站点国家代码
+    #[serde(default)]
+    pub localized_products: HashMap<String, LocalizedProductResult>,
+}
+
+impl LocalizedProductResponse {
+    /// 目标站点匹配到的商品, 未匹配返回 None
+    pub fn localized(&self, country_code: &str) -> Option<&LocalizationProduct> {
+        self.localized_products
+            .get(country_code)
+            .and_then(|item| item.localized_product.as_ref())
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedProductResult {
+    pub localized_product: Option<LocalizationProduct>,
+    pub status: Option<String>,
+    pub error: Option<String>,
+}
@@ -0,0 +1,447 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use crate::util::wrap_include_optional;
+use anyhow::Result;
+use bon::{bon, Builder};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_with::skip_serializing_none;
+use std::sync::Arc;
+use strum::{AsRefStr, Display};
+
+const PORTFOLIO_MEDIA_TYPE: &str = "application/vnd.spPortfolio.v3+json";
+
+// ==============================================================================
+// ListPortfolios 请求类
+// ==============================================================================
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct ListPortfolios {
+    ads_client: Arc<AdsClient>,
+    filter: ListPortfoliosFilter,
+}
+
+#[bon]
+impl ListPortfolios {
+    #[builder]
+    pub async fn fetch(self, next_token: Option<&str>) -> Result<ListPortfoliosResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+}
+
+impl AdsOperation for ListPortfolios {
+    type Response = ListPortfoliosResponse;
+
+    fn path(&self) -> String {
+        "/portfolios/list".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(PORTFOLIO_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(PORTFOLIO_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
+    }
+}
+
+// --- ListPortfolios 过滤器 -------------------
+
+#[skip_serializing_none]
+#[derive(Serialize, Builder, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPortfoliosFilter {
+    pub include_extended_data_fields: Option<bool>,
+    pub max_results: Option<usize>,
+    pub name_filter: Option<PortfolioNameFilter>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|s| s.to_string()).collect())]
+    pub portfolio_id_filter: Option<Vec<String>>,
+
+    #[serde(serialize_with = "wrap_include_optional")]
+    pub state_filter: Option<Vec<PortfolioState>>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioNameFilter {
+    pub include: Vec<String>,
+    pub query_term_match_type: PortfolioQueryTermMatchType,
+}
+
+impl PortfolioNameFilter {
+    pub fn exact(names: Vec<&str>) -> Self {
+        Self {
+            include: names.into_iter().map(|item| item.to_string()).collect(),
+            query_term_match_type: PortfolioQueryTermMatchType::ExactMatch,
+        }
+    }
+
+    pub fn broad(names: Vec<&str>) -> Self {
+        Self {
+            include: names.into_iter().map(|item| item.to_string()).collect(),
+            query_term_match_type: PortfolioQueryTermMatchType::BroadMatch,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PortfolioQueryTermMatchType {
+    BroadMatch,
+    ExactMatch,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPortfoliosResponse {
+    pub next_token: Option<String>,
+    pub portfolios: Vec<Portfolio>,
+    pub total_results: Option<usize>,
+}
+
+// ==============================================================================
+// 创建组合
+// ==============================================================================
+#[derive(Builder, Debug)]
+pub struct CreatePortfolios {
+    ads_client: Arc<AdsClient>,
+    portfolios: Vec<PortfolioCreate>,
+}
+
+impl CreatePortfolios {
+    pub async fn fetch(self) -> Result<PortfoliosOperationResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreatePortfolios {
+    type Response = PortfoliosOperationResponse;
+
+    fn path(&self) -> String {
+        "/portfolios".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(PORTFOLIO_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(PORTFOLIO_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "portfolios": serde_json::to_value(&self.portfolios)?,
+        })))
+    }
+}
+
+// --- 创建组合的单个项 -------------------
+#[skip_serializing_none]
+#[derive(Builder, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(on(String, into))]
+pub struct PortfolioCreate {
+    #[builder(start_fn)]
+    pub name: String,
+
+    #[builder(field)]
+    pub budget: Option<PortfolioBudget>,
+
+    #[builder(default = PortfolioState::Enabled)]
+    pub state: PortfolioState,
+}
+
+impl<S: portfolio_create_builder::State> PortfolioCreateBuilder<S> {
+    /// 在指定日期范围内的总预算上限
+    pub fn date_range_budget(
+        mut self,
+        amount: f64,
+        currency_code: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Self {
+        self.budget = Some(PortfolioBudget::date_range(
+            amount,
+            currency_code,
+            start_date,
+            end_date,
+        ));
+        self
+    }
+
+    /// 每月自动重置的预算上限, end_date 为空表示不结束
+    pub fn monthly_recurring_budget(
+        mut self,
+        amount: f64,
+        currency_code: &str,
+        end_date: Option<NaiveDate>,
+    ) -> Self {
+        self.budget = Some(PortfolioBudget::monthly_recurring(
+            amount,
+            currency_code,
+            end_date,
+        ));
+        self
+    }
+
+    /// 不设预算上限
+    pub fn no_cap(mut self) -> Self {
+        self.budget = Some(PortfolioBudget::no_cap());
+        self
+    }
+}
+
+// ==============================================================================
+// 更新组合
+// ==============================================================================
+#[derive(Builder, Debug)]
+pub struct UpdatePortfolios {
+    ads_client: Arc<AdsClient>,
+    portfolios: Vec<PortfolioUpdate>,
+}
+
+impl UpdatePortfolios {
+    pub async fn fetch(self) -> Result<PortfoliosOperationResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for UpdatePortfolios {
+    type Response = PortfoliosOperationResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Put
+    }
+
+    fn path(&self) -> String {
+        "/portfolios".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(PORTFOLIO_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(PORTFOLIO_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "portfolios": serde_json::to_value(&self.portfolios)?,
+        })))
+    }
+}
+
+// --- 更新组合的单个项 -------------------
+#[skip_serializing_none]
+#[derive(Builder, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(on(String, into))]
+pub struct PortfolioUpdate {
+    #[builder(start_fn)]
+    pub portfolio_id: String,
+
+    #[builder(field)]
+    pub budget: Option<PortfolioBudget>,
+
+    pub name: Option<String>,
+    pub state: Option<PortfolioState>,
+}
+
+impl<S: portfolio_update_builder::State> PortfolioUpdateBuilder<S> {
+    pub fn date_range_budget(
+        mut self,
+        amount: f64,
+        currency_code: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Self {
+        self.budget = Some(PortfolioBudget::date_range(
+            amount,
+            currency_code,
+            start_date,
+            end_date,
+        ));
+        self
+    }
+
+    pub fn monthly_recurring_budget(
+        mut self,
+        amount: f64,
+        currency_code: &str,
+        end_date: Option<NaiveDate>,
+    ) -> Self {
+        self.budget = Some(PortfolioBudget::monthly_recurring(
+            amount,
+            currency_code,
+            end_date,
+        ));
+        self
+    }
+
+    pub fn no_cap(mut self) -> Self {
+        self.budget = Some(PortfolioBudget::no_cap());
+        self
+    }
+}
+
+// --- 创建/更新的响应结构 -------------------
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfoliosOperationResponse {
+    pub portfolios: PortfoliosMultiStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfoliosMultiStatus {
+    pub error: Option<Vec<PortfolioMutationError>>,
+    pub success: Option<Vec<PortfolioMutationSuccess>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioMutationSuccess {
+    pub index: i64,
+    pub portfolio: Option<Portfolio>,
+    pub portfolio_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioMutationError {
+    pub errors: Vec<PortfolioMutationErrorDetail>,
+    pub index: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioMutationErrorDetail {
+    pub error_type: String,
+    pub error_value: serde_json::Value,
+}
+
+// ==============================================================================
+// 基本数据
+// ==============================================================================
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Portfolio {
+    pub budget: Option<PortfolioBudget>,
+    pub extended_data: Option<PortfolioExtendedData>,
+    // 组合当前花费是否仍在预算内
+    pub in_budget: Option<bool>,
+    pub name: String,
+    pub portfolio_id: String,
+    pub state: PortfolioState,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioBudget {
+    pub amount: Option<f64>,
+    pub currency_code: Option<String>,
+    pub end_date: Option<NaiveDate>,
+    pub policy: PortfolioBudgetPolicy,
+    pub start_date: Option<NaiveDate>,
+}
+
+impl PortfolioBudget {
+    pub fn date_range(
+        amount: f64,
+        currency_code: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            currency_code: Some(currency_code.to_string()),
+            end_date: Some(end_date),
+            policy: PortfolioBudgetPolicy::DateRange,
+            start_date: Some(start_date),
+        }
+    }
+
+    pub fn monthly_recurring(
+        amount: f64,
+        currency_code: &str,
+        end_date: Option<NaiveDate>,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            currency_code: Some(currency_code.to_string()),
+            end_date,
+            policy: PortfolioBudgetPolicy::MonthlyRecurring,
+            start_date: None,
+        }
+    }
+
+    pub fn no_cap() -> Self {
+        Self {
+            amount: None,
+            currency_code: None,
+            end_date: None,
+            policy: PortfolioBudgetPolicy::NoCap,
+            start_date: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioExtendedData {
+    #[serde(rename = "creationDateTime")]
+    pub creation_datetime: Option<DateTime<Utc>>,
+    #[serde(rename = "lastUpdateDateTime")]
+    pub last_update_datetime: Option<DateTime<Utc>>,
+    pub serving_status: Option<PortfolioServingStatus>,
+    pub status_reasons: Option<Vec<String>>,
+}
+
+// --- 预算策略枚举 -------------------
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PortfolioBudgetPolicy {
+    DateRange,
+    MonthlyRecurring,
+    NoCap,
+}
+
+// --- 状态枚举 -------------------
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Display, AsRefStr)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PortfolioState {
+    Enabled,
+    Paused,
+    Archived,
+    #[serde(other)]
+    Other,
+}
+
+// --- 服务状态枚举 -------------------
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PortfolioServingStatus {
+    PortfolioStatusEnabled,
+    PortfolioPaused,
+    PortfolioArchived,
+    PortfolioOutOfBudget,
+    PendingStartDate,
+    Ended,
+    #[serde(other)]
+    Other,
+}
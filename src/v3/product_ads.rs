@@ -1,12 +1,18 @@
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{json, Value};
 
+use crate::batch::BatchOperation;
 use crate::client::AdsClient;
+use crate::multi_status::MultiStatus;
+use crate::operation::AdsOperation;
+use crate::pagination::{collect_all, paginate, Collected};
 use crate::util::wrap_include_optional;
 use anyhow::Result;
 use bon::Builder;
 use chrono::{DateTime, Utc};
+use futures::Stream;
 use serde_with::skip_serializing_none;
+use std::fmt;
 use std::sync::Arc;
 
 // ==============================================================================
@@ -18,21 +24,52 @@ use std::sync::Arc;
 pub struct ListProductAds {
     ads_client: Arc<AdsClient>,
     filter: ListProductAdsFilter,
+    // stream/collect_all 合计最多返回的条数, 不设置时遍历所有页
+    limit: Option<usize>,
 }
 
 impl ListProductAds {
     pub async fn fetch(self) -> Result<ListProductAdsResponse> {
-        let filter = serde_json::to_value(&self.filter)?;
-        let response = self
-            .ads_client
-            .post()
-            .path("/sp/productAds/list")
-            .json_body(filter)
-            .content_type("application/vnd.spProductAd.v3+json")
-            .call()
-            .await?;
-        let data = response.json::<ListProductAdsResponse>().await?;
-        Ok(data)
+        let next_token = self.filter.next_token.clone();
+        self.fetch_page(next_token).await
+    }
+
+    /// 自动跟随 nextToken 遍历所有页, 每页大小为 filter 的 max_results
+    pub fn stream(&self) -> impl Stream<Item = Result<ProductAdItem>> + '_ {
+        let first_token = self.filter.next_token.clone();
+        paginate(self.limit, move |next_token| {
+            let next_token = next_token.or_else(|| first_token.clone());
+            async move {
+                let res = self.fetch_page(next_token).await?;
+                Ok((res.product_ads, res.next_token))
+            }
+        })
+    }
+
+    pub async fn collect_all(&self) -> Collected<ProductAdItem> {
+        collect_all(self.stream()).await
+    }
+
+    async fn fetch_page(&self, next_token: Option<String>) -> Result<ListProductAdsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token.as_deref()))
+            .await
+    }
+}
+
+impl AdsOperation for ListProductAds {
+    type Response = ListProductAdsResponse;
+
+    fn path(&self) -> String {
+        "/sp/productAds/list".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/vnd.spProductAd.v3+json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(serde_json::to_value(&self.filter)?))
     }
 }
 
@@ -81,18 +118,38 @@ pub struct CreateProductAds {
 
 impl CreateProductAds {
     pub async fn fetch(self) -> Result<CreateProductAdsResponse> {
-        let json_body = json!({
-            "productAds": serde_json::to_value(&self.product_ads)?,
-        });
-        let response = self
-            .ads_client
-            .post()
-            .path("/sp/productAds")
-            .json_body(json_body)
-            .call()
-            .await?;
-        let data = response.json::<CreateProductAdsResponse>().await?;
-        Ok(data)
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateProductAds {
+    type Response = CreateProductAdsResponse;
+
+    fn path(&self) -> String {
+        "/sp/productAds".to_string()
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({ "productAds": self.product_ads })))
+    }
+}
+
+impl BatchOperation for CreateProductAds {
+    type Input = ProductAdsItemForCreate;
+    type Item = CreateProductAdsMutationSuccess;
+    type Error = CreateProductAdsMutationErrorDetail;
+
+    const MAX_BATCH_SIZE: usize = 1000;
+
+    fn into_parts(self) -> (Arc<AdsClient>, Vec<ProductAdsItemForCreate>) {
+        (self.ads_client, self.product_ads)
+    }
+
+    fn from_parts(ads_client: Arc<AdsClient>, product_ads: Vec<ProductAdsItemForCreate>) -> Self {
+        Self {
+            ads_client,
+            product_ads,
+        }
     }
 }
 // --- 创建广告产品的单个项 -------------------
@@ -130,6 +187,32 @@ pub struct CreateProductAdsOperationResponse {
     pub success: Option<Vec<CreateProductAdsMutationSuccess>>,
 }
 
+impl From<CreateProductAdsOperationResponse>
+    for MultiStatus<CreateProductAdsMutationSuccess, CreateProductAdsMutationErrorDetail>
+{
+    fn from(res: CreateProductAdsOperationResponse) -> Self {
+        MultiStatus::from_parts(
+            res.success
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index as usize, item)),
+            [],
+            res.error
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| (item.index as usize, item.errors)),
+        )
+    }
+}
+
+impl From<CreateProductAdsResponse>
+    for MultiStatus<CreateProductAdsMutationSuccess, CreateProductAdsMutationErrorDetail>
+{
+    fn from(res: CreateProductAdsResponse) -> Self {
+        res.product_ads.into()
+    }
+}
+
 // --- 成功的响应结构 -------------------
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -151,7 +234,20 @@ pub struct CreateProductAdsMutationError {
 #[serde(rename_all = "camelCase")]
 pub struct CreateProductAdsMutationErrorDetail {
     pub error_type: String,
-    pub error_value: serde_json::Value,
+    pub error_value: CreateProductAdsErrorValue,
+}
+
+impl fmt::Display for CreateProductAdsMutationErrorDetail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.error_value.selector() {
+            Some(selector) => write!(
+                f,
+                "{} {}: {}",
+                self.error_type, selector.reason, selector.message
+            ),
+            None => write!(f, "{}", self.error_type),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -173,6 +269,33 @@ pub enum CreateProductAdsErrorValue {
     RangeError(CreateProductAdsMutationErrorSelector),
     ThrottledError(CreateProductAdsMutationErrorSelector),
     UnsupportedOperationError(CreateProductAdsMutationErrorSelector),
+    // 未收录的错误类型保留原始 JSON
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl CreateProductAdsErrorValue {
+    pub fn selector(&self) -> Option<&CreateProductAdsMutationErrorSelector> {
+        match self {
+            CreateProductAdsErrorValue::AdEligibilityError(selector)
+            | CreateProductAdsErrorValue::AsinOwnershipError(selector)
+            | CreateProductAdsErrorValue::BillingError(selector)
+            | CreateProductAdsErrorValue::DuplicateValueError(selector)
+            | CreateProductAdsErrorValue::EntityNotFoundError(selector)
+            | CreateProductAdsErrorValue::EntityQuotaError(selector)
+            | CreateProductAdsErrorValue::EntityStateError(selector)
+            | CreateProductAdsErrorValue::InternalServerError(selector)
+            | CreateProductAdsErrorValue::MalformedValueError(selector)
+            | CreateProductAdsErrorValue::MissingValueError(selector)
+            | CreateProductAdsErrorValue::OtherError(selector)
+            | CreateProductAdsErrorValue::ParentEntityError(selector)
+            | CreateProductAdsErrorValue::ProductIdentifierError(selector)
+            | CreateProductAdsErrorValue::RangeError(selector)
+            | CreateProductAdsErrorValue::ThrottledError(selector)
+            | CreateProductAdsErrorValue::UnsupportedOperationError(selector) => Some(selector),
+            CreateProductAdsErrorValue::Unknown(_) => None,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
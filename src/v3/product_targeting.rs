@@ -1,10 +1,22 @@
+use crate::ads_v1::sp::types::targets::SPProductCategoryRefinement;
 use crate::client::AdsClient;
+use crate::operation::{AdsOperation, HttpMethod};
+use crate::pagination::{collect_all, paginate, Collected};
 use anyhow::Result;
-use bon::Builder;
-use serde::Deserialize;
-use serde_json::json;
+use bon::{bon, Builder};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use super::types::enums::Locale;
+
+const PRODUCT_TARGETING_MEDIA_TYPE: &str = "application/vnd.spproducttargeting.v3+json";
+const PRODUCT_TARGETING_RESPONSE_MEDIA_TYPE: &str =
+    "application/vnd.spproducttargetingresponse.v3+json";
+
 #[derive(Builder)]
 #[builder(on(String, into))]
 pub struct ListNegativeTargetsBrandsSearch {
@@ -14,21 +26,23 @@ pub struct ListNegativeTargetsBrandsSearch {
 
 impl ListNegativeTargetsBrandsSearch {
     pub async fn fetch(self) -> Result<Vec<ListNegativeTargetsBrandsSearchItem>> {
-        let filter = json!({
-            "keyword": self.keyword,
-        });
-        let res = self
-            .ads_client
-            .post()
-            .path("/sp/negativeTargets/brands/search")
-            .json_body(filter)
-            .content_type("application/vnd.spproducttargeting.v3+json")
-            .call()
-            .await?;
-        let data = res
-            .json::<Vec<ListNegativeTargetsBrandsSearchItem>>()
-            .await?;
-        Ok(data)
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for ListNegativeTargetsBrandsSearch {
+    type Response = Vec<ListNegativeTargetsBrandsSearchItem>;
+
+    fn path(&self) -> String {
+        "/sp/negativeTargets/brands/search".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(PRODUCT_TARGETING_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({ "keyword": self.keyword })))
     }
 }
 
@@ -38,3 +52,763 @@ pub struct ListNegativeTargetsBrandsSearchItem {
     pub id: String,
     pub name: String,
 }
+
+// ==============================================================================
+// 否定品牌推荐
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct ListNegativeTargetsBrandsRecommendations {
+    ads_client: Arc<AdsClient>,
+}
+
+impl ListNegativeTargetsBrandsRecommendations {
+    pub async fn fetch(self) -> Result<Vec<ListNegativeTargetsBrandsSearchItem>> {
+        let data = self.ads_client.execute(&self).await?;
+        Ok(data.recommendations)
+    }
+}
+
+impl AdsOperation for ListNegativeTargetsBrandsRecommendations {
+    type Response = NegativeTargetsBrandsRecommendationsResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/sp/negativeTargets/brands/recommendations".to_string()
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(PRODUCT_TARGETING_RESPONSE_MEDIA_TYPE)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeTargetsBrandsRecommendationsResponse {
+    #[serde(default)]
+    pub recommendations: Vec<ListNegativeTargetsBrandsSearchItem>,
+}
+
+impl ListNegativeTargetsBrandsSearchItem {
+    /// 在广告组下否定该品牌
+    pub fn to_negative_target(&self, campaign_id: &str, ad_group_id: &str) -> NegativeTargetCreate {
+        NegativeTargetCreate {
+            ad_group_id: ad_group_id.to_string(),
+            campaign_id: campaign_id.to_string(),
+            expression: vec![NegativeTargetExpression::brand(&self.id)],
+            state: NegativeTargetState::Enabled,
+        }
+    }
+
+    /// 在整个广告活动下否定该品牌
+    pub fn to_campaign_negative_target(&self, campaign_id: &str) -> CampaignNegativeTargetCreate {
+        CampaignNegativeTargetCreate {
+            campaign_id: campaign_id.to_string(),
+            expression: vec![NegativeTargetExpression::brand(&self.id)],
+            state: NegativeTargetState::Enabled,
+        }
+    }
+}
+
+// ==============================================================================
+// 创建否定商品定向 (广告组/广告活动)
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct CreateNegativeTargets {
+    ads_client: Arc<AdsClient>,
+    negative_targets: Vec<NegativeTargetCreate>,
+}
+
+impl CreateNegativeTargets {
+    pub async fn fetch(self) -> Result<NegativeTargetsOperationResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateNegativeTargets {
+    type Response = NegativeTargetsOperationResponse;
+
+    fn path(&self) -> String {
+        "/sp/negativeTargets".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/vnd.spNegativeTargetingClause.v3+json")
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/vnd.spNegativeTargetingClause.v3+json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "negativeTargetingClauses": self.negative_targets,
+        })))
+    }
+}
+
+#[derive(Builder)]
+pub struct CreateCampaignNegativeTargets {
+    ads_client: Arc<AdsClient>,
+    negative_targets: Vec<CampaignNegativeTargetCreate>,
+}
+
+impl CreateCampaignNegativeTargets {
+    pub async fn fetch(self) -> Result<NegativeTargetsOperationResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CreateCampaignNegativeTargets {
+    type Response = NegativeTargetsOperationResponse;
+
+    fn path(&self) -> String {
+        "/sp/campaignNegativeTargets".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some("application/vnd.spCampaignNegativeTargetingClause.v3+json")
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/vnd.spCampaignNegativeTargetingClause.v3+json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "campaignNegativeTargetingClauses": self.negative_targets,
+        })))
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeTargetCreate {
+    pub ad_group_id: String,
+    pub campaign_id: String,
+    pub expression: Vec<NegativeTargetExpression>,
+    pub state: NegativeTargetState,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignNegativeTargetCreate {
+    pub campaign_id: String,
+    pub expression: Vec<NegativeTargetExpression>,
+    pub state: NegativeTargetState,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeTargetExpression {
+    #[serde(rename = "type")]
+    pub expression_type: NegativeTargetExpressionType,
+    pub value: String,
+}
+
+impl NegativeTargetExpression {
+    pub fn brand(brand_id: &str) -> Self {
+        Self {
+            expression_type: NegativeTargetExpressionType::AsinBrandSameAs,
+            value: brand_id.to_string(),
+        }
+    }
+
+    pub fn asin(asin: &str) -> Self {
+        Self {
+            expression_type: NegativeTargetExpressionType::AsinSameAs,
+            value: asin.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NegativeTargetExpressionType {
+    AsinBrandSameAs,
+    AsinSameAs,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NegativeTargetState {
+    Enabled,
+    Paused,
+}
+
+// 广告组和广告活动级别的返回结构相同, 只是外层字段名不同
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeTargetsOperationResponse {
+    #[serde(alias = "campaignNegativeTargetingClauses")]
+    pub negative_targeting_clauses: NegativeTargetsMultiStatus,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeTargetsMultiStatus {
+    pub error: Option<Vec<NegativeTargetMutationError>>,
+    pub success: Option<Vec<NegativeTargetMutationSuccess>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeTargetMutationSuccess {
+    pub index: i64,
+    pub target_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeTargetMutationError {
+    pub errors: Vec<NegativeTargetMutationErrorDetail>,
+    pub index: i64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeTargetMutationErrorDetail {
+    pub error_type: String,
+    pub error_value: serde_json::Value,
+}
+
+// ==============================================================================
+// 可投放品类树
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct ListTargetableCategories {
+    ads_client: Arc<AdsClient>,
+    locale: Option<Locale>,
+}
+
+impl ListTargetableCategories {
+    pub async fn fetch(self) -> Result<CategoryTree> {
+        let data = self.ads_client.execute(&self).await?;
+        CategoryTree::from_json(&data.category_tree)
+    }
+}
+
+impl AdsOperation for ListTargetableCategories {
+    type Response = ListTargetableCategoriesResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        "/sp/targets/categories".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![];
+        if let Some(locale) = &self.locale {
+            query.push(("locale", locale.code()));
+        }
+        query
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(PRODUCT_TARGETING_RESPONSE_MEDIA_TYPE)
+    }
+}
+
+// 接口把整棵品类树序列化成字符串放在 categoryTree 字段里
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTargetableCategoriesResponse {
+    pub category_tree: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CategoryNode {
+    #[serde(rename = "id")]
+    pub id: String,
+    #[serde(rename = "na")]
+    pub name: String,
+    // 是否可直接作为品类定向
+    #[serde(rename = "ta", default)]
+    pub targetable: bool,
+    #[serde(rename = "tn")]
+    pub translated_name: Option<String>,
+    #[serde(rename = "ch", default)]
+    pub children: Vec<CategoryNode>,
+}
+
+/// 可导航的品类树, 按 id 建立索引, 用于把 product_category_id 解析成可读路径
+#[derive(Debug, Clone, Default)]
+pub struct CategoryTree {
+    roots: Vec<CategoryNode>,
+    // id -> 从根节点开始的下标路径
+    index: HashMap<String, Vec<usize>>,
+}
+
+impl CategoryTree {
+    pub fn new(roots: Vec<CategoryNode>) -> Self {
+        let mut index = HashMap::new();
+        let mut stack: Vec<(Vec<usize>, &CategoryNode)> = roots
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (vec![i], node))
+            .collect();
+        while let Some((position, node)) = stack.pop() {
+            for (i, child) in node.children.iter().enumerate() {
+                let mut child_position = position.clone();
+                child_position.push(i);
+                stack.push((child_position, child));
+            }
+            index.insert(node.id.clone(), position);
+        }
+        Self { roots, index }
+    }
+
+    pub fn from_json(category_tree: &str) -> Result<Self> {
+        let roots = serde_json::from_str::<Vec<CategoryNode>>(category_tree)?;
+        Ok(Self::new(roots))
+    }
+
+    pub fn roots(&self) -> &[CategoryNode] {
+        &self.roots
+    }
+
+    pub fn get(&self, id: &str) -> Option<&CategoryNode> {
+        self.ancestors(id)?.pop()
+    }
+
+    /// 从根节点到该品类(含自身)的节点列表
+    pub fn ancestors(&self, id: &str) -> Option<Vec<&CategoryNode>> {
+        let position = self.index.get(id)?;
+        let mut nodes = Vec::with_capacity(position.len());
+        let mut current = &self.roots;
+        for &i in position {
+            let node = &current[i];
+            nodes.push(node);
+            current = &node.children;
+        }
+        Some(nodes)
+    }
+
+    pub fn parent(&self, id: &str) -> Option<&CategoryNode> {
+        let mut ancestors = self.ancestors(id)?;
+        ancestors.pop();
+        ancestors.pop()
+    }
+
+    pub fn children(&self, id: &str) -> Option<&[CategoryNode]> {
+        self.get(id).map(|node| node.children.as_slice())
+    }
+
+    /// 可读路径, 例如 "Electronics/Accessories/Cases"
+    pub fn path(&self, id: &str) -> Option<String> {
+        let names: Vec<&str> = self
+            .ancestors(id)?
+            .into_iter()
+            .map(|node| node.name.as_str())
+            .collect();
+        Some(names.join("/"))
+    }
+
+    /// 解析 ads_v1 定向里的 product_category_id
+    pub fn resolve(&self, refinement: &SPProductCategoryRefinement) -> Option<String> {
+        self.path(refinement.product_category_id.as_deref()?)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CategoryNode> {
+        let mut stack: Vec<&CategoryNode> = self.roots.iter().rev().collect();
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+}
+
+// ==============================================================================
+// 基于 ASIN 的品类推荐
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct CategoryRecommendationsForASINs {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    asins: Vec<String>,
+    #[builder(default = false)]
+    include_ancestor: bool,
+    locale: Option<Locale>,
+}
+
+impl CategoryRecommendationsForASINs {
+    pub async fn fetch(self) -> Result<CategoryRecommendationsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CategoryRecommendationsForASINs {
+    type Response = CategoryRecommendationsResponse;
+
+    fn path(&self) -> String {
+        "/sp/targets/categories/recommendations".to_string()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        self.locale
+            .iter()
+            .map(|locale| ("locale", locale.code()))
+            .collect()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(PRODUCT_TARGETING_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(PRODUCT_TARGETING_RESPONSE_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "asins": self.asins,
+            "includeAncestor": self.include_ancestor,
+        })))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryRecommendationsResponse {
+    pub categories: Vec<RecommendedCategory>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendedCategory {
+    pub can_be_targeted: Option<bool>,
+    pub id: String,
+    pub name: String,
+    pub parent_id: Option<String>,
+    pub path: Option<String>,
+    pub translated_name: Option<String>,
+    pub translated_path: Option<String>,
+}
+
+// ==============================================================================
+// 品类细化条件 (品牌/年龄段/类型)
+// ==============================================================================
+
+#[derive(Builder)]
+#[builder(on(String, into))]
+pub struct CategoryRefinements {
+    ads_client: Arc<AdsClient>,
+    category_id: String,
+    locale: Option<Locale>,
+}
+
+impl CategoryRefinements {
+    pub async fn fetch(self) -> Result<CategoryRefinementsResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for CategoryRefinements {
+    type Response = CategoryRefinementsResponse;
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    fn path(&self) -> String {
+        format!("/sp/targets/category/{}/refinements", self.category_id)
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        self.locale
+            .iter()
+            .map(|locale| ("locale", locale.code()))
+            .collect()
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(PRODUCT_TARGETING_RESPONSE_MEDIA_TYPE)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryRefinementsResponse {
+    #[serde(default)]
+    pub age_ranges: Vec<CategoryRefinementItem>,
+    #[serde(default)]
+    pub brands: Vec<CategoryRefinementItem>,
+    #[serde(default)]
+    pub genres: Vec<CategoryRefinementItem>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryRefinementItem {
+    pub id: String,
+    pub name: String,
+    pub translated_name: Option<String>,
+}
+
+// ==============================================================================
+// 品类定向可覆盖的商品数
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct TargetableProductsCount {
+    ads_client: Arc<AdsClient>,
+    filter: TargetableProductsCountFilter,
+}
+
+impl TargetableProductsCount {
+    pub async fn fetch(self) -> Result<TargetableProductsCountResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for TargetableProductsCount {
+    type Response = TargetableProductsCountResponse;
+
+    fn path(&self) -> String {
+        "/sp/targets/products/count".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(PRODUCT_TARGETING_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(PRODUCT_TARGETING_RESPONSE_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        let filter = serde_json::to_value(&self.filter)?;
+        Ok(Some(filter))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Builder)]
+#[builder(on(String, into))]
+#[serde(rename_all = "camelCase")]
+pub struct TargetableProductsCountFilter {
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    pub age_ranges: Option<Vec<String>>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    pub brands: Option<Vec<String>>,
+    pub category: String,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    pub genres: Option<Vec<String>>,
+    pub is_prime_shipping: Option<bool>,
+    pub price_range_lower_bound: Option<f64>,
+    pub price_range_upper_bound: Option<f64>,
+    pub rating_range_lower_bound: Option<f64>,
+    pub rating_range_upper_bound: Option<f64>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetableProductsCountResponse {
+    pub product_count: Option<i64>,
+    pub status: Option<String>,
+}
+
+// ==============================================================================
+// 商品定向推荐 (竞品/互补 ASIN)
+// ==============================================================================
+
+const PRODUCT_RECOMMENDATION_MEDIA_TYPE: &str = "application/vnd.spproductrecommendation.v3+json";
+
+#[derive(Builder)]
+pub struct ProductRecommendations {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    ad_asins: Vec<String>,
+    // 每页数量, 最大 1000
+    #[builder(default = 100)]
+    count: i32,
+    locale: Option<Locale>,
+    // stream/collect_all 合计最多返回的条数, 不设置时遍历所有页
+    limit: Option<usize>,
+}
+
+#[bon]
+impl ProductRecommendations {
+    #[builder]
+    pub async fn fetch(&self, cursor: Option<&str>) -> Result<ProductRecommendationsResponse> {
+        let fields = cursor
+            .map(|cursor| vec![("cursor", json!(cursor))])
+            .unwrap_or_default();
+        self.ads_client
+            .execute(&self.with_body_fields(fields))
+            .await
+    }
+
+    /// 自动跟随 nextCursor 遍历所有页
+    pub fn stream(&self) -> impl Stream<Item = Result<RecommendedProduct>> + '_ {
+        paginate(self.limit, move |cursor| async move {
+            let res = self.fetch().maybe_cursor(cursor.as_deref()).call().await?;
+            Ok(res.into_page())
+        })
+    }
+
+    pub async fn collect_all(&self) -> Collected<RecommendedProduct> {
+        collect_all(self.stream()).await
+    }
+}
+
+impl AdsOperation for ProductRecommendations {
+    type Response = ProductRecommendationsResponse;
+
+    fn path(&self) -> String {
+        "/sp/targets/products/recommendations".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(PRODUCT_RECOMMENDATION_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/vnd.spproductrecommendationresponse.asins.v3+json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(product_recommendations_body(
+            &self.ad_asins,
+            self.count,
+            self.locale.as_ref(),
+        )))
+    }
+}
+
+/// 按主题分组返回推荐 ASIN (如 "Top converting targets")
+#[derive(Builder)]
+pub struct ProductRecommendationsByTheme {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    ad_asins: Vec<String>,
+    #[builder(default = 100)]
+    count: i32,
+    locale: Option<Locale>,
+    // stream/collect_all 合计最多返回的主题数, 不设置时遍历所有页
+    limit: Option<usize>,
+}
+
+#[bon]
+impl ProductRecommendationsByTheme {
+    #[builder]
+    pub async fn fetch(
+        &self,
+        cursor: Option<&str>,
+    ) -> Result<ProductRecommendationsByThemeResponse> {
+        let fields = cursor
+            .map(|cursor| vec![("cursor", json!(cursor))])
+            .unwrap_or_default();
+        self.ads_client
+            .execute(&self.with_body_fields(fields))
+            .await
+    }
+
+    /// 自动跟随 nextCursor 遍历所有页
+    pub fn stream(&self) -> impl Stream<Item = Result<ProductRecommendationTheme>> + '_ {
+        paginate(self.limit, move |cursor| async move {
+            let res = self.fetch().maybe_cursor(cursor.as_deref()).call().await?;
+            Ok(res.into_page())
+        })
+    }
+
+    pub async fn collect_all(&self) -> Collected<ProductRecommendationTheme> {
+        collect_all(self.stream()).await
+    }
+}
+
+impl AdsOperation for ProductRecommendationsByTheme {
+    type Response = ProductRecommendationsByThemeResponse;
+
+    fn path(&self) -> String {
+        "/sp/targets/products/recommendations".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(PRODUCT_RECOMMENDATION_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some("application/vnd.spproductrecommendationresponse.themes.v3+json")
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(product_recommendations_body(
+            &self.ad_asins,
+            self.count,
+            self.locale.as_ref(),
+        )))
+    }
+}
+
+fn product_recommendations_body(
+    ad_asins: &[String],
+    count: i32,
+    locale: Option<&Locale>,
+) -> serde_json::Value {
+    let mut json_body = json!({
+        "adAsins": ad_asins,
+        "count": count,
+    });
+    if let Some(locale) = locale {
+        json_body["locale"] = json!(locale.code());
+    }
+    json_body
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductRecommendationsResponse {
+    pub next_cursor: Option<String>,
+    pub previous_cursor: Option<String>,
+    #[serde(default)]
+    pub recommendations: Vec<RecommendedProduct>,
+}
+
+impl ProductRecommendationsResponse {
+    /// (本页数据, nextCursor), 供 pagination::paginate 使用
+    pub fn into_page(self) -> (Vec<RecommendedProduct>, Option<String>) {
+        (self.recommendations, self.next_cursor)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductRecommendationsByThemeResponse {
+    pub next_cursor: Option<String>,
+    pub previous_cursor: Option<String>,
+    #[serde(default)]
+    pub themes: Vec<ProductRecommendationTheme>,
+}
+
+impl ProductRecommendationsByThemeResponse {
+    /// (本页数据, nextCursor), 供 pagination::paginate 使用
+    pub fn into_page(self) -> (Vec<ProductRecommendationTheme>, Option<String>) {
+        (self.themes, self.next_cursor)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductRecommendationTheme {
+    pub expression: Option<String>,
+    #[serde(default)]
+    pub recommendations: Vec<RecommendedProduct>,
+    pub theme: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendedProduct {
+    pub recommended_asin: String,
+}
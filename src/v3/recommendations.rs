@@ -0,0 +1,306 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::AdsClient;
+use crate::operation::AdsOperation;
+use anyhow::Result;
+use bon::{bon, Builder};
+use std::sync::Arc;
+use strum::{AsRefStr, Display, EnumString};
+
+pub const RECOMMENDATIONS_MEDIA_TYPE: &str = "application/vnd.recommendations.v1+json";
+
+// ==============================================================================
+// 获取推荐 (预算/新关键词/竞价调整)
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct ListRecommendations {
+    #[builder(field)]
+    filters: Vec<RecommendationFilter>,
+    ads_client: Arc<AdsClient>,
+    #[builder(default = 100)]
+    max_results: i32,
+}
+
+impl<S: list_recommendations_builder::State> ListRecommendationsBuilder<S> {
+    pub fn filter(mut self, field: RecommendationFilterField, values: Vec<&str>) -> Self {
+        self.filters.push(RecommendationFilter {
+            field,
+            values: values.into_iter().map(|item| item.to_string()).collect(),
+        });
+        self
+    }
+
+    pub fn recommendation_types(self, types: Vec<RecommendationType>) -> Self {
+        let values = types.iter().map(|item| item.as_ref()).collect();
+        self.filter(RecommendationFilterField::RecommendationType, values)
+    }
+
+    pub fn campaign_ids(self, campaign_ids: Vec<&str>) -> Self {
+        self.filter(RecommendationFilterField::CampaignId, campaign_ids)
+    }
+
+    pub fn ad_group_ids(self, ad_group_ids: Vec<&str>) -> Self {
+        self.filter(RecommendationFilterField::AdGroupId, ad_group_ids)
+    }
+
+    pub fn status(self, status: RecommendationStatus) -> Self {
+        self.filter(RecommendationFilterField::Status, vec![status.as_ref()])
+    }
+}
+
+#[bon]
+impl ListRecommendations {
+    #[builder]
+    pub async fn fetch(&self, next_token: Option<&str>) -> Result<ListRecommendationsResponse> {
+        self.ads_client
+            .execute(&self.with_next_token(next_token))
+            .await
+    }
+}
+
+impl AdsOperation for ListRecommendations {
+    type Response = ListRecommendationsResponse;
+
+    fn path(&self) -> String {
+        "/recommendations/list".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(RECOMMENDATIONS_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(RECOMMENDATIONS_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(json!({
+            "filters": self.filters,
+            "maxResults": self.max_results,
+        })))
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendationFilter {
+    pub field: RecommendationFilterField,
+    pub values: Vec<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRecommendationsResponse {
+    pub next_token: Option<String>,
+    #[serde(default)]
+    pub recommendations: Vec<Recommendation>,
+}
+
+// ==============================================================================
+// 应用 / 忽略推荐
+// ==============================================================================
+
+#[derive(Builder)]
+pub struct ApplyRecommendations {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    recommendation_ids: Vec<String>,
+}
+
+impl ApplyRecommendations {
+    pub async fn fetch(self) -> Result<RecommendationsOperationResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for ApplyRecommendations {
+    type Response = RecommendationsOperationResponse;
+
+    fn path(&self) -> String {
+        "/recommendations/apply".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(RECOMMENDATIONS_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(RECOMMENDATIONS_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(
+            json!({ "recommendationIds": self.recommendation_ids }),
+        ))
+    }
+}
+
+#[derive(Builder)]
+pub struct DismissRecommendations {
+    ads_client: Arc<AdsClient>,
+    #[builder(with=|items: Vec<&str>| items.into_iter().map(|item| item.to_string()).collect())]
+    recommendation_ids: Vec<String>,
+}
+
+impl DismissRecommendations {
+    pub async fn fetch(self) -> Result<RecommendationsOperationResponse> {
+        self.ads_client.execute(&self).await
+    }
+}
+
+impl AdsOperation for DismissRecommendations {
+    type Response = RecommendationsOperationResponse;
+
+    fn path(&self) -> String {
+        "/recommendations/dismiss".to_string()
+    }
+
+    fn content_type(&self) -> Option<&'static str> {
+        Some(RECOMMENDATIONS_MEDIA_TYPE)
+    }
+
+    fn accept(&self) -> Option<&'static str> {
+        Some(RECOMMENDATIONS_MEDIA_TYPE)
+    }
+
+    fn body(&self) -> Result<Option<Value>> {
+        Ok(Some(
+            json!({ "recommendationIds": self.recommendation_ids }),
+        ))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendationsOperationResponse {
+    #[serde(default)]
+    pub error: Vec<RecommendationError>,
+    #[serde(default)]
+    pub success: Vec<RecommendationSuccess>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendationSuccess {
+    pub index: usize,
+    pub recommendation_id: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendationError {
+    pub index: usize,
+    pub recommendation_id: Option<String>,
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+// ==============================================================================
+// 基本数据
+// ==============================================================================
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Recommendation {
+    pub recommendation_id: String,
+    pub recommendation_type: RecommendationType,
+    pub ad_product: Option<String>,
+    pub ad_group_id: Option<String>,
+    pub campaign_id: Option<String>,
+    pub status: Option<RecommendationStatus>,
+    pub rationale: Option<String>,
+    pub recommended_action: Option<serde_json::Value>,
+}
+
+impl Recommendation {
+    /// 按 recommendationType 解析 recommendedAction, 未知类型保留原始 JSON
+    pub fn payload(&self) -> Result<RecommendationPayload> {
+        let action = self
+            .recommended_action
+            .clone()
+            .unwrap_or(serde_json::Value::Null);
+        let payload = match self.recommendation_type {
+            RecommendationType::CampaignBudget => {
+                RecommendationPayload::CampaignBudget(serde_json::from_value(action)?)
+            }
+            RecommendationType::NewKeyword => {
+                RecommendationPayload::NewKeyword(serde_json::from_value(action)?)
+            }
+            RecommendationType::KeywordBid => {
+                RecommendationPayload::KeywordBid(serde_json::from_value(action)?)
+            }
+            RecommendationType::Other => RecommendationPayload::Other(action),
+        };
+        Ok(payload)
+    }
+}
+
+#[derive(Debug)]
+pub enum RecommendationPayload {
+    CampaignBudget(CampaignBudgetRecommendationAction),
+    NewKeyword(NewKeywordRecommendationAction),
+    KeywordBid(KeywordBidRecommendationAction),
+    Other(serde_json::Value),
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignBudgetRecommendationAction {
+    pub current_budget: Option<f64>,
+    pub recommended_budget: f64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NewKeywordRecommendationAction {
+    pub keyword_text: String,
+    pub match_type: String,
+    pub suggested_bid: Option<f64>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct KeywordBidRecommendationAction {
+    pub keyword_id: String,
+    pub current_bid: Option<f64>,
+    pub recommended_bid: f64,
+}
+
+// --- 枚举 -------------------
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecommendationFilterField {
+    RecommendationType,
+    CampaignId,
+    AdGroupId,
+    Status,
+}
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Display, AsRefStr, EnumString,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecommendationType {
+    CampaignBudget,
+    NewKeyword,
+    KeywordBid,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Display, AsRefStr, EnumString,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecommendationStatus {
+    Published,
+    Applied,
+    Dismissed,
+    Expired,
+    #[serde(other)]
+    Other,
+}
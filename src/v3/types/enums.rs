@@ -37,6 +37,17 @@ pub enum Locale {
     ZhCn, // zh_CN
 }
 
+impl Locale {
+    /// Locale 序列化为 EN_US, 查询参数和本地化接口需要 en_US
+    pub fn code(&self) -> String {
+        let code = self.to_string();
+        match code.split_once('_') {
+            Some((language, country)) => format!("{}_{}", language.to_lowercase(), country),
+            None => code,
+        }
+    }
+}
+
 #[derive(Serialize, Debug, AsRefStr, Display, EnumString)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[serde(rename_all="SCREAMING_SNAKE_CASE")]
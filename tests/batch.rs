@@ -0,0 +1,108 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use amazon_ads_api::ads_v1::sp::ads::{DelAds, OperationAdsResponse};
+use amazon_ads_api::batch::{execute_batched_with, split_batches};
+use amazon_ads_api::multi_status::MultiStatus;
+use amazon_ads_api::operation::AdsOperation;
+use anyhow::anyhow;
+use serde_json::json;
+mod common;
+
+#[test]
+fn batched_chunk_ranges() {
+    let ads_client = common::offline_ads_client();
+    let ad_ids: Vec<String> = (0..2500).map(|item| item.to_string()).collect();
+    let operation = DelAds::builder()
+        .ads_client(Arc::new(ads_client))
+        .ad_ids(ad_ids.iter().map(|item| item.as_str()).collect())
+        .build();
+
+    let chunks = split_batches(operation);
+    let ranges: Vec<_> = chunks.iter().map(|(range, _)| range.clone()).collect();
+    assert_eq!(ranges, vec![0..1000, 1000..2000, 2000..2500]);
+
+    // 每批的请求体正好是原始输入中对应区间的 id
+    for (range, operation) in &chunks {
+        let body = operation.to_request().unwrap().body.unwrap();
+        assert_eq!(body, json!({ "adIds": ad_ids[range.clone()] }));
+    }
+}
+
+#[test]
+fn merge_rewrites_indices() {
+    let mut status: MultiStatus<&str, String> = MultiStatus::default();
+    status.merge(MultiStatus::from_parts([(0, "c"), (1, "d")], [], []), 2);
+    status.merge(
+        MultiStatus::from_parts([(0, "a")], [], [(1, vec!["bad".to_string()])]),
+        0,
+    );
+    let indices: Vec<usize> = status.entries.iter().map(|entry| entry.index).collect();
+    assert_eq!(indices, vec![0, 1, 2, 3]);
+    assert_eq!(status.failed_indices(), vec![1]);
+}
+
+#[tokio::test]
+async fn execute_batched_merges_chunks_and_errors() {
+    let ads_client = common::offline_ads_client();
+    let ad_ids: Vec<String> = (0..3500).map(|item| item.to_string()).collect();
+    let operation = DelAds::builder()
+        .ads_client(Arc::new(ads_client))
+        .ad_ids(ad_ids.iter().map(|item| item.as_str()).collect())
+        .build();
+
+    let in_flight = AtomicUsize::new(0);
+    let max_in_flight = AtomicUsize::new(0);
+    let batched = execute_batched_with(operation, 2, |operation: DelAds| {
+        let in_flight = &in_flight;
+        let max_in_flight = &max_in_flight;
+        async move {
+            let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            max_in_flight.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            let body = operation.to_request()?.body.unwrap();
+            let first_id = body["adIds"][0].as_str().unwrap().to_string();
+            if first_id == "1000" {
+                return Err(anyhow!("throttled"));
+            }
+            // 每批第 2 条(批内 index 1)删除失败
+            let res: OperationAdsResponse = serde_json::from_value(json!({
+                "error": [{"index": 1, "errors": [{"code": "NOT_FOUND", "message": "not found"}]}]
+            }))?;
+            Ok(res)
+        }
+    })
+    .await;
+
+    assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+
+    let mut indices: Vec<usize> = batched
+        .status
+        .entries
+        .iter()
+        .map(|entry| entry.index)
+        .collect();
+    indices.sort();
+    assert_eq!(indices, vec![1, 2001, 3001]);
+
+    let ranges: Vec<_> = batched
+        .chunk_errors
+        .iter()
+        .map(|chunk_error| chunk_error.range.clone())
+        .collect();
+    assert_eq!(ranges, vec![1000..2000]);
+    assert_eq!(batched.chunk_errors[0].error.to_string(), "throttled");
+
+    let failed = batched.failed_indices();
+    assert_eq!(failed.len(), 1003);
+    assert_eq!(failed[0], 1);
+    assert_eq!(
+        &failed[1..1001],
+        (1000..2000).collect::<Vec<_>>().as_slice()
+    );
+    assert_eq!(&failed[1001..], &[2001, 3001]);
+    assert!(!batched.is_all_success());
+}
@@ -53,3 +53,15 @@ pub fn profile_id() -> String {
     dotenv().ok();
     env::var("AMAZON_PROFILE_ID").expect("Missing AMAZON_PROFILE_ID in .env file")
 }
+
+/// 不需要 .env 的客户端, 只用于构造请求, 不能真正发送
+#[allow(dead_code)]
+pub fn offline_ads_client() -> client::AdsClient {
+    client::AdsClient::builder()
+        .seller_id("seller")
+        .country_code("UK")
+        .client_id("client_id")
+        .client_secret("client_secret")
+        .refresh_token("refresh_token")
+        .build()
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use amazon_ads_api::exports::{
+    CreateExport, ExportResponse, ExportState, ExportStatus, ExportType, ExportedCampaign,
+    ExportedTarget, GetExport,
+};
+mod common;
+
+#[test]
+fn parse_exported_targets() {
+    let data = r#"[{"targetId":"t1","adGroupId":"g1","campaignId":"c1","adProduct":"SPONSORED_PRODUCTS","state":"ENABLED","targetType":"KEYWORD","negative":false,"bid":0.75,"targetDetails":{"keywordTarget":{"keyword":"shoes","matchType":"EXACT"}}}]"#;
+    let targets: Vec<ExportedTarget> = serde_json::from_str(data).unwrap();
+    assert_eq!(targets[0].state, ExportState::Enabled);
+    assert_eq!(targets[0].bid, Some(0.75));
+}
+
+#[test]
+fn parse_unknown_export_status() {
+    let data = r#"{"exportId":"e1","status":"QUEUED"}"#;
+    let res: ExportResponse = serde_json::from_str(data).unwrap();
+    assert_eq!(res.status, ExportStatus::Other);
+}
+
+#[tokio::test]
+async fn fetch_document_rejects_mismatched_entity() {
+    let ads_client = common::offline_ads_client();
+
+    let get_export = GetExport::builder()
+        .ads_client(Arc::new(ads_client))
+        .export_type(ExportType::Targets)
+        .export_id("e1")
+        .build();
+    let res = get_export
+        .fetch_document::<ExportedCampaign>("https://example.com/export.json.gz")
+        .await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn export_targets_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+
+    let res = CreateExport::builder()
+        .ads_client(ads_client.clone())
+        .export_type(ExportType::Targets)
+        .state_filter(vec![ExportState::Enabled, ExportState::Paused])
+        .build()
+        .fetch()
+        .await
+        .unwrap();
+
+    let targets = GetExport::builder()
+        .ads_client(ads_client)
+        .export_type(ExportType::Targets)
+        .export_id(res.export_id)
+        .build()
+        .wait_and_download::<ExportedTarget>(Duration::from_secs(5), Duration::from_secs(600))
+        .await
+        .unwrap();
+    dbg!(targets.len());
+}
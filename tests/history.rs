@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use amazon_ads_api::history::{
+    HistoryChangeType, HistoryEntityType, HistoryResponse, HistoryValue, ListHistory,
+};
+use amazon_ads_api::operation::AdsOperation;
+use chrono::{Duration, Utc};
+mod common;
+
+#[test]
+fn parse_history_values() {
+    let data = r#"{"events":[
+        {"changeType":"BUDGET_AMOUNT","entityId":"c1","entityType":"CAMPAIGN","previousValue":"10.0","newValue":"25.5","timestamp":1714550400000},
+        {"changeType":"STATUS","entityId":"k1","entityType":"KEYWORD","previousValue":"enabled","newValue":"paused","timestamp":1714550400000},
+        {"changeType":"SOMETHING_NEW","entityId":"a1","entityType":"AD","newValue":"x","timestamp":1714550400000},
+        {"changeType":"BUDGET_AMOUNT","entityId":"p1","entityType":"PORTFOLIO","newValue":"100","timestamp":1714550400000}
+    ],"totalRecords":4}"#;
+    let res: HistoryResponse = serde_json::from_str(data).unwrap();
+    assert_eq!(res.events[0].before(), HistoryValue::Amount(10.0));
+    assert_eq!(res.events[0].after().as_f64(), Some(25.5));
+    assert_eq!(
+        res.events[1].after(),
+        HistoryValue::State("PAUSED".to_string())
+    );
+    assert_eq!(res.events[2].change_type, HistoryChangeType::Other);
+    assert_eq!(res.events[2].before(), HistoryValue::Empty);
+    assert_eq!(res.events[3].entity_type, HistoryEntityType::Other);
+}
+
+#[test]
+fn history_count_and_page_offset() {
+    let req = ListHistory::builder()
+        .ads_client(Arc::new(common::offline_ads_client()))
+        .from_date(Utc::now() - Duration::days(1))
+        .to_date(Utc::now())
+        .count(500)
+        .build();
+    let body = req.to_request().unwrap().body.unwrap();
+    assert_eq!(body["count"], 200);
+
+    let page = |events: usize, max_page_number: Option<i32>| {
+        let event = serde_json::json!({
+            "changeType": "STATUS", "entityId": "c1", "entityType": "CAMPAIGN", "timestamp": 0
+        });
+        serde_json::from_value::<HistoryResponse>(serde_json::json!({
+            "events": vec![event; events],
+            "maxPageNumber": max_page_number,
+        }))
+        .unwrap()
+    };
+    assert_eq!(page(2, Some(3)).next_page_offset(0, 2), Some(1));
+    assert_eq!(page(2, Some(3)).next_page_offset(3, 2), None);
+    assert_eq!(page(1, Some(3)).next_page_offset(0, 2), None);
+    assert_eq!(page(0, None).next_page_offset(0, 2), None);
+    assert_eq!(page(2, None).next_page_offset(5, 2), Some(6));
+}
+
+#[tokio::test]
+async fn list_history_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let req = ListHistory::builder()
+        .ads_client(ads_client)
+        .from_date(Utc::now() - Duration::days(30))
+        .to_date(Utc::now())
+        .entity(
+            HistoryEntityType::Campaign,
+            vec![],
+            vec![HistoryChangeType::BudgetAmount, HistoryChangeType::Status],
+        )
+        .entity(
+            HistoryEntityType::Keyword,
+            vec![],
+            vec![HistoryChangeType::BidAmount],
+        )
+        .build();
+    let res = req.fetch().call().await.unwrap();
+    let all = req.collect_all().await;
+    dbg!(all.items.len(), res.total_records);
+    for event in &res.events {
+        dbg!(
+            &event.entity_id,
+            &event.change_type,
+            event.before(),
+            event.after()
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use amazon_ads_api::ads_v1::sp_global::ads::OperationAdsResponse;
+use amazon_ads_api::ads_v1::sp_global::types::ads::SPGlobalAd;
+use amazon_ads_api::multi_status::{EntryStatus, MultiStatus};
+use amazon_ads_api::v3::product_ads::{
+    CreateProductAdsErrorValue, CreateProductAdsMutationErrorDetail,
+    CreateProductAdsMutationSuccess, CreateProductAdsResponse,
+};
+
+#[test]
+fn create_product_ads_multi_status() {
+    let data = r#"{
+        "productAds": {
+            "success": [{"adId": "a1", "index": 0}],
+            "error": [
+                {"index": 2, "errors": [{"errorType": "entityNotFoundError", "errorValue": {
+                    "entityNotFoundError": {"cause": {"location": "$[2].adGroupId"}, "message": "ad group not found", "reason": "ENTITY_NOT_FOUND"}
+                }}]},
+                {"index": 1, "errors": [{"errorType": "newError", "errorValue": {"newError": {"message": "x"}}}]}
+            ]
+        }
+    }"#;
+    let res: CreateProductAdsResponse = serde_json::from_str(data).unwrap();
+    let status: MultiStatus<CreateProductAdsMutationSuccess, CreateProductAdsMutationErrorDetail> =
+        res.into();
+
+    let indices: Vec<usize> = status.entries.iter().map(|entry| entry.index).collect();
+    assert_eq!(indices, vec![0, 1, 2]);
+    assert_eq!(status.failed_indices(), vec![1, 2]);
+
+    let inputs = vec!["asin-0", "asin-1", "asin-2"];
+    assert_eq!(status.failed_inputs(&inputs), vec![&"asin-1", &"asin-2"]);
+
+    let EntryStatus::Error(errors) = &status.entries[2].status else {
+        panic!("index 2 should fail");
+    };
+    assert!(matches!(
+        errors[0].error_value,
+        CreateProductAdsErrorValue::EntityNotFoundError(_)
+    ));
+    let EntryStatus::Error(errors) = &status.entries[1].status else {
+        panic!("index 1 should fail");
+    };
+    assert!(matches!(
+        errors[0].error_value,
+        CreateProductAdsErrorValue::Unknown(_)
+    ));
+
+    let err = status.into_result().unwrap_err().to_string();
+    assert!(err.contains("ad group not found"));
+}
+
+#[test]
+fn partial_success_is_not_success() {
+    let status: MultiStatus<&str, String> =
+        MultiStatus::from_parts([(0, "a")], [(1, "b", vec!["DE failed".to_string()])], []);
+    assert_eq!(status.successes().count(), 2);
+    assert_eq!(status.failed_indices(), vec![1]);
+    assert!(status.into_result().is_err());
+
+    let status: MultiStatus<&str, String> = MultiStatus::from_parts([(1, "b"), (0, "a")], [], []);
+    assert_eq!(status.into_result().unwrap(), vec!["a", "b"]);
+}
+
+#[test]
+fn global_ads_multi_status() {
+    let ad = r#"{
+        "adGroupId": "g1", "adId": "a1", "campaignId": "c1",
+        "creationDateTime": "2026-01-01T00:00:00Z", "lastUpdatedDateTime": "2026-01-01T00:00:00Z",
+        "creative": {"productCreative": {"productCreativeSettings": {"advertisedProduct": {"productIdType": "ASIN"}}}},
+        "marketplaces": ["US", "DE"], "state": "ENABLED"
+    }"#;
+    let data = format!(
+        r#"{{
+            "success": [{{"index": 0, "ad": {ad}}}],
+            "partialSuccess": [{{"index": 1, "ad": {ad}, "errors": [{{"code": "BAD_REQUEST", "message": "DE failed"}}]}}],
+            "error": [{{"index": 2, "errors": [{{"code": "BAD_REQUEST", "message": "invalid asin"}}]}}]
+        }}"#
+    );
+    let res: OperationAdsResponse = serde_json::from_str(&data).unwrap();
+    let status: MultiStatus<SPGlobalAd> = res.into();
+    assert_eq!(status.successes().count(), 2);
+    assert_eq!(status.failed_indices(), vec![1, 2]);
+
+    let inputs = vec!["asin-0", "asin-1", "asin-2"];
+    assert_eq!(status.failed_inputs(&inputs), vec![&"asin-1", &"asin-2"]);
+    assert!(status.into_result().is_err());
+}
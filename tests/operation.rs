@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use amazon_ads_api::ads_v1::sp::ads::{DelAds, ListAds, ListAdsFilter};
+use amazon_ads_api::ads_v1::sp_global::ads::UpdateAds;
+use amazon_ads_api::operation::{AdsOperation, HttpMethod};
+use amazon_ads_api::reporting::GetReport;
+use amazon_ads_api::stream::subscriptions::ListSubscriptions;
+use serde_json::json;
+mod common;
+
+#[test]
+fn operation_to_request() {
+    let ads_client = Arc::new(common::offline_ads_client());
+
+    let request = DelAds::builder()
+        .ads_client(ads_client.clone())
+        .ad_ids(vec!["1", "2"])
+        .build()
+        .to_request()
+        .unwrap();
+    assert_eq!(request.method, HttpMethod::Post);
+    assert_eq!(request.path, "/adsApi/v1/delete/ads");
+    assert_eq!(request.body, Some(json!({ "adIds": ["1", "2"] })));
+
+    let request = GetReport::builder()
+        .ads_client(ads_client)
+        .report_id("report-1")
+        .build()
+        .to_request()
+        .unwrap();
+    assert_eq!(request.method, HttpMethod::Get);
+    assert_eq!(request.path, "/reporting/reports/report-1");
+    assert!(request.body.is_none());
+}
+
+#[test]
+fn operation_with_params() {
+    let ads_client = Arc::new(common::offline_ads_client());
+
+    let list_ads = ListAds::builder()
+        .ads_client(ads_client.clone())
+        .filter(ListAdsFilter::builder().max_results(10).build())
+        .build();
+    let request = list_ads.to_request().unwrap();
+    assert_eq!(request.path, "/adsApi/v1/query/ads");
+    assert!(request.body.as_ref().unwrap().get("nextToken").is_none());
+
+    let request = list_ads
+        .with_next_token(Some("token"))
+        .to_request()
+        .unwrap();
+    let body = request.body.unwrap();
+    assert_eq!(body["nextToken"], "token");
+    assert_eq!(body["maxResults"], 10);
+
+    let list_subscriptions = ListSubscriptions::builder()
+        .ads_client(ads_client.clone())
+        .build();
+    let request = list_subscriptions
+        .with_query(vec![("startingToken", "token".to_string())])
+        .to_request()
+        .unwrap();
+    assert_eq!(request.method, HttpMethod::Get);
+    assert_eq!(request.query.last().unwrap().0, "startingToken");
+    assert!(request.body.is_none());
+
+    let request = UpdateAds::builder()
+        .ads_client(ads_client)
+        .ads(vec![])
+        .build()
+        .to_request()
+        .unwrap();
+    assert_eq!(request.path, "/adsApi/v1/update/ads");
+    assert_eq!(request.body, Some(json!({ "ads": [] })));
+}
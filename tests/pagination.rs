@@ -0,0 +1,31 @@
+use amazon_ads_api::pagination::{collect_all, paginate};
+use anyhow::anyhow;
+use futures::stream;
+
+#[tokio::test]
+async fn collect_all_keeps_partial_results() {
+    let pages = vec![Ok(1), Ok(2), Err(anyhow!("throttled")), Ok(3)];
+    let collected = collect_all(stream::iter(pages)).await;
+    assert_eq!(collected.items, vec![1, 2]);
+    assert!(!collected.is_complete());
+    assert!(collected.into_result().is_err());
+
+    let collected = collect_all(stream::iter(vec![Ok::<_, anyhow::Error>(1), Ok(2)])).await;
+    assert_eq!(collected.into_result().unwrap(), vec![1, 2]);
+}
+
+#[tokio::test]
+async fn paginate_stops_at_limit() {
+    let mut calls = 0;
+    let stream = paginate(Some(3), |next_token: Option<String>| {
+        calls += 1;
+        let page = next_token.map_or(0, |token| token.parse::<i32>().unwrap());
+        async move {
+            let items = vec![page * 2, page * 2 + 1];
+            Ok((items, Some((page + 1).to_string())))
+        }
+    });
+    let collected = collect_all(stream).await;
+    assert_eq!(collected.into_result().unwrap(), vec![0, 1, 2]);
+    assert_eq!(calls, 2);
+}
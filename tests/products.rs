@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
-use amazon_ads_api::products::{ProductMetadata, ProductMetadataFilter};
+use amazon_ads_api::products::{
+    ProductEligibility, ProductMetadata, ProductMetadataFilter, ProductMetadataLookup,
+};
 mod common;
 
 #[tokio::test]
@@ -19,3 +21,52 @@ async fn list_product() {
     let response = api.fetch().call().await.unwrap();
     dbg!(&response);
 }
+
+#[tokio::test]
+async fn product_eligibility() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let response = ProductEligibility::builder()
+        .ads_client(ads_client)
+        .asins(vec!["B0CHX3QBCH", "B0CHWRXH8B"])
+        .build()
+        .fetch()
+        .await
+        .unwrap();
+    dbg!(response.eligible());
+    for item in response.ineligible() {
+        dbg!(&item.product_details, item.reason_codes());
+    }
+}
+
+#[tokio::test]
+async fn product_metadata_stream() {
+    use futures::TryStreamExt;
+
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = ProductMetadataFilter::builder()
+        .check_item_details(false)
+        .build();
+    let api = ProductMetadata::builder()
+        .ads_client(ads_client.clone())
+        .filter(filter)
+        .build();
+    let mut stream = Box::pin(api.stream(100));
+    let mut count = 0;
+    while let Some(item) = stream.try_next().await.unwrap() {
+        count += 1;
+        dbg!(&item.asin);
+    }
+    dbg!(count);
+
+    let items = ProductMetadataLookup::builder()
+        .ads_client(ads_client)
+        .asins(vec!["B0CHX3QBCH", "B0CHWRXH8B"])
+        .chunk_size(1)
+        .build()
+        .fetch()
+        .await
+        .unwrap();
+    dbg!(items.len());
+}
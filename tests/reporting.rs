@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use amazon_ads_api::reporting::{CreateReport, CreateReportFilter, GetReport};
+use amazon_ads_api::reporting::{CreateReport, CreateReportFilter, GetReport, ReportJob};
 use amazon_ads_api::reporting_type::{
     CreateReportStatus, SpCampaignsColumns, SpTargetingColumn, TimeUnit,
 };
@@ -163,3 +163,80 @@ async fn get_report_document() {
     let json_value: Value = serde_json::from_reader(decoder).unwrap();
     dbg!(&json_value);
 }
+
+#[tokio::test]
+async fn report_job() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+
+    let filter = CreateReportFilter::builder()
+        .by_campaigns(
+            TimeUnit::Summary,
+            vec![
+                SpCampaignsColumns::CampaignId,
+                SpCampaignsColumns::Impressions,
+                SpCampaignsColumns::Clicks,
+                SpCampaignsColumns::Cost,
+            ],
+        )
+        .start_date(NaiveDate::parse_from_str("2026-03-01", "%Y-%m-%d").unwrap())
+        .end_date(NaiveDate::parse_from_str("2026-03-09", "%Y-%m-%d").unwrap())
+        .build();
+
+    let mut job = ReportJob::builder()
+        .ads_client(ads_client.clone())
+        .filter(filter)
+        .build();
+    let report_id = job.start().await.unwrap();
+    dbg!(&report_id);
+
+    // 模拟进程重启后用保存的 report_id 继续
+    let rows: Vec<Value> = ReportJob::builder()
+        .ads_client(ads_client)
+        .report_id(report_id)
+        .build()
+        .run_as()
+        .await
+        .unwrap();
+    dbg!(rows.len());
+}
+
+#[tokio::test]
+async fn report_job_requires_filter_or_report_id() {
+    let ads_client = common::offline_ads_client();
+    let mut job = ReportJob::builder()
+        .ads_client(Arc::new(ads_client))
+        .build();
+    assert!(job.start().await.is_err());
+    assert!(job.report_id().is_none());
+}
+
+#[tokio::test]
+async fn fetch_document_expired_url_returns_status_error() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    // 模拟已过期的 S3 链接
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = [0u8; 1024];
+        let _ = socket.read(&mut buf).await;
+        let _ = socket
+            .write_all(b"HTTP/1.1 403 Forbidden\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+            .await;
+    });
+
+    let ads_client = common::offline_ads_client();
+    let err = GetReport::builder()
+        .ads_client(Arc::new(ads_client))
+        .report_id("r1")
+        .build()
+        .fetch_document(&format!("http://{}/report.json.gz", addr))
+        .await
+        .unwrap_err();
+    let status = err
+        .downcast_ref::<reqwest::Error>()
+        .and_then(|err| err.status());
+    assert_eq!(status, Some(reqwest::StatusCode::FORBIDDEN));
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use amazon_ads_api::sb::ads::{CreateProductCollectionAds, ListAds, ListAdsFilter};
+use amazon_ads_api::sb::keywords::{CreateKeywords, ListKeywords, ListKeywordsFilter};
+use amazon_ads_api::sb::targets::{CreateTargets, ListTargets};
+use amazon_ads_api::sb::types::ads::{
+    SBLandingPage, SBProductCollectionAdCreate, SBProductCollectionCreative,
+};
+use amazon_ads_api::sb::types::enums::{SBKeywordMatchType, SBTargetFilterType};
+use amazon_ads_api::sb::types::keywords::SBKeywordCreate;
+use amazon_ads_api::sb::types::targets::SBTargetCreate;
+mod common;
+
+#[tokio::test]
+async fn list_sb_ads_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = ListAdsFilter::builder().max_results(10).build();
+    let response = ListAds::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_sb_product_collection_ads_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let creative = SBProductCollectionCreative::builder()
+        .asins(vec!["B0CHRYHRJL", "B0CHS1HBKD", "B0CHRZ4CDR"])
+        .brand_logo_asset_id("amzn1.assetlibrary.asset1.0000")
+        .brand_name("test brand")
+        .headline("test headline")
+        .build();
+    let body = SBProductCollectionAdCreate::builder("494576620738611")
+        .name("test-sb-ad")
+        .creative(creative)
+        .landing_page(SBLandingPage::product_list(vec![
+            "B0CHRYHRJL",
+            "B0CHS1HBKD",
+            "B0CHRZ4CDR",
+        ]))
+        .build();
+    let api = CreateProductCollectionAds::builder()
+        .ads(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn list_sb_keywords_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = ListKeywordsFilter::builder().count(10).build();
+    let response = ListKeywords::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_sb_keywords_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SBKeywordCreate::builder()
+        .ad_group_id(494576620738611)
+        .campaign_id(494576620738612)
+        .keyword_text("phone case")
+        .match_type(SBKeywordMatchType::Broad)
+        .bid(1.0)
+        .build();
+    let res = CreateKeywords::builder()
+        .keywords(vec![body])
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn list_sb_targets_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let response = ListTargets::builder()
+        .ads_client(ads_client)
+        .filter(SBTargetFilterType::AdGroupId, vec!["494576620738611"])
+        .max_results(10)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_sb_targets_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SBTargetCreate::builder()
+        .ad_group_id(494576620738611)
+        .campaign_id(494576620738612)
+        .bid(0.8)
+        .asin("B0CHRYHRJL")
+        .build();
+    let res = CreateTargets::builder()
+        .targets(vec![body])
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
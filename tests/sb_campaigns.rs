@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use amazon_ads_api::sb::ad_groups::{CreateAdGroups, ListAdGroups, ListAdGroupsFilter};
+use amazon_ads_api::sb::campaigns::{
+    CreateCampaigns, ListCampaigns, ListCampaignsFilter, UpdateCampaigns,
+};
+use amazon_ads_api::sb::types::ad_groups::SBAdGroupCreate;
+use amazon_ads_api::sb::types::campaigns::{SBCampaignCreate, SBCampaignUpdate};
+use amazon_ads_api::sb::types::enums::{SBBidAdjustmentPlacement, SBBidOptimizationStrategy};
+mod common;
+
+#[tokio::test]
+async fn list_sb_campaigns_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = ListCampaignsFilter::builder().max_results(10).build();
+    let response = ListCampaigns::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_sb_campaigns_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SBCampaignCreate::builder("test-sb-campaign")
+        .budget(20.0)
+        .placement_bid_adjustment(SBBidAdjustmentPlacement::Home, 10)
+        .build();
+    let api = CreateCampaigns::builder()
+        .campaigns(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn update_sb_campaigns_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SBCampaignUpdate::builder("494576620738611")
+        .budget(25.0)
+        .bid_optimization(SBBidOptimizationStrategy::MaximizeImmediateSales)
+        .build();
+    let api = UpdateCampaigns::builder()
+        .campaigns(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
+
+#[test]
+fn sb_bidding_placement_overrides_optimization() {
+    let body = SBCampaignUpdate::builder("494576620738611")
+        .bid_optimization(SBBidOptimizationStrategy::MaximizeImmediateSales)
+        .placement_bid_adjustment(SBBidAdjustmentPlacement::Home, 10)
+        .build();
+    let bidding = serde_json::to_value(&body).unwrap()["bidding"].clone();
+    assert_eq!(bidding["bidOptimization"], false);
+    assert!(bidding.get("bidOptimizationStrategy").is_none());
+
+    let body = SBCampaignCreate::builder("test-sb-campaign")
+        .budget(20.0)
+        .placement_bid_adjustment(SBBidAdjustmentPlacement::Home, 10)
+        .bid_optimization(SBBidOptimizationStrategy::MaximizeImmediateSales)
+        .build();
+    let bidding = serde_json::to_value(&body).unwrap()["bidding"].clone();
+    assert_eq!(bidding["bidOptimization"], true);
+    assert!(bidding.get("bidAdjustmentsByPlacement").is_none());
+}
+
+#[tokio::test]
+async fn list_sb_ad_groups_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = ListAdGroupsFilter::builder().max_results(10).build();
+    let response = ListAdGroups::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_sb_ad_groups_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SBAdGroupCreate::builder("494576620738611")
+        .name("test-sb-ad-group")
+        .build();
+    let api = CreateAdGroups::builder()
+        .ad_groups(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use amazon_ads_api::sd::ad_groups::{CreateAdGroups, ListAdGroups};
+use amazon_ads_api::sd::campaigns::{CreateCampaigns, ListCampaigns, SDListFilter};
+use amazon_ads_api::sd::creatives::{CreateCreatives, ListCreatives};
+use amazon_ads_api::sd::negative_targets::CreateNegativeTargets;
+use amazon_ads_api::sd::product_ads::CreateProductAds;
+use amazon_ads_api::sd::targets::{CreateTargets, ListTargets};
+use amazon_ads_api::sd::types::ad_groups::SDAdGroupCreate;
+use amazon_ads_api::sd::types::campaigns::SDCampaignCreate;
+use amazon_ads_api::sd::types::creatives::{SDCreativeCreate, SDCreativeProperties};
+use amazon_ads_api::sd::types::enums::{SDBidOptimization, SDState, SDTactic};
+use amazon_ads_api::sd::types::product_ads::SDProductAdCreate;
+use amazon_ads_api::sd::types::targets::{
+    SDNegativeTargetCreate, SDTargetCreate, SDTargetingPredicateNested,
+};
+mod common;
+
+#[tokio::test]
+async fn list_sd_campaigns_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = SDListFilter::builder()
+        .count(10)
+        .state_filter(vec![SDState::Enabled, SDState::Paused])
+        .build();
+    let response = ListCampaigns::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_sd_campaigns_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SDCampaignCreate::builder("test-sd-audiences")
+        .tactic(SDTactic::T00030)
+        .budget(10.0)
+        .start_date("20261101")
+        .build();
+    let res = CreateCampaigns::builder()
+        .campaigns(vec![body])
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn create_sd_ad_groups_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SDAdGroupCreate::builder(494576620738611)
+        .name("test-sd-ad-group")
+        .default_bid(0.5)
+        .bid_optimization(SDBidOptimization::Conversions)
+        .build();
+    let res = CreateAdGroups::builder()
+        .ad_groups(vec![body])
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn list_sd_ad_groups_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let response = ListAdGroups::builder()
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_sd_product_ads_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SDProductAdCreate::builder(494576620738611)
+        .sku("TEST-SKU-01")
+        .build();
+    let res = CreateProductAds::builder()
+        .product_ads(vec![body])
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn create_sd_targets_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let views = SDTargetCreate::builder(494576620738611)
+        .views(SDTargetingPredicateNested::exact_product(), 30)
+        .bid(0.6)
+        .build();
+    let purchases = SDTargetCreate::builder(494576620738611)
+        .purchases(SDTargetingPredicateNested::category("12345678"), 90)
+        .build();
+    let audience = SDTargetCreate::builder(494576620738611)
+        .audience("441166883564765133")
+        .build();
+    let contextual = SDTargetCreate::builder(494576620738611)
+        .category("12345678")
+        .brand("87654321")
+        .build();
+    let res = CreateTargets::builder()
+        .targets(vec![views, purchases, audience, contextual])
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn list_sd_targets_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = SDListFilter::builder()
+        .ad_group_id_filter(vec!["494576620738611"])
+        .build();
+    let response = ListTargets::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_sd_negative_targets_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SDNegativeTargetCreate::builder(494576620738611)
+        .asin("B0CHRYHRJL")
+        .build();
+    let res = CreateNegativeTargets::builder()
+        .negative_targets(vec![body])
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn sd_creatives_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let properties = SDCreativeProperties::builder()
+        .headline("test headline")
+        .build();
+    let body = SDCreativeCreate::builder(494576620738611)
+        .properties(properties)
+        .build();
+    let res = CreateCreatives::builder()
+        .creatives(vec![body])
+        .ads_client(ads_client.clone())
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+    let response = ListCreatives::builder()
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&response);
+}
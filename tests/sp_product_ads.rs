@@ -43,3 +43,16 @@ async fn create_product_ads_test() {
         .unwrap();
     dbg!(&response);
 }
+
+#[tokio::test]
+async fn list_all_product_ads_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = ListProductAdsFilter::builder().max_results(100).build();
+    let request = ListProductAds::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build();
+    let collected = request.collect_all().await;
+    dbg!(collected.items.len(), &collected.error);
+}
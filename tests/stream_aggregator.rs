@@ -0,0 +1,109 @@
+use amazon_ads_api::stream::aggregator::HourlyAggregator;
+use amazon_ads_api::stream::messages::StreamMessage;
+use chrono::{NaiveDate, TimeDelta};
+use serde_json::json;
+
+fn traffic(id: &str, time: &str, clicks: i64, cost: f64) -> StreamMessage {
+    StreamMessage::from_value(json!({
+        "idempotency_id": id, "dataset_id": "sp-traffic", "marketplace_id": "A1F83G8C2ARO7P",
+        "currency": "GBP", "advertiser_id": "ENTITY1", "campaign_id": "c1", "ad_group_id": "g1",
+        "keyword_id": "k1", "time_window_start": time, "clicks": clicks, "impressions": clicks * 10,
+        "cost": cost
+    }))
+    .unwrap()
+}
+
+fn conversion(id: &str, time: &str, purchases: i64, sales: f64) -> StreamMessage {
+    StreamMessage::from_value(json!({
+        "idempotency_id": id, "dataset_id": "sp-conversion", "marketplace_id": "A1F83G8C2ARO7P",
+        "currency": "GBP", "advertiser_id": "ENTITY1", "campaign_id": "c1", "ad_group_id": "g1",
+        "keyword_id": "k1", "time_window_start": time,
+        "attributed_conversions_7d": purchases, "attributed_sales_7d": sales
+    }))
+    .unwrap()
+}
+
+#[test]
+fn aggregate_hourly_in_advertiser_timezone() {
+    let mut aggregator = HourlyAggregator::from_timezone("Europe/London").unwrap();
+
+    // UTC 23:00 在英国夏令时是次日 00:00
+    assert!(aggregator.push(&traffic("t1", "2024-05-01T23:00:00Z", 3, 1.5)));
+    assert!(!aggregator.push(&traffic("t1", "2024-05-01T23:00:00Z", 3, 1.5)));
+    assert!(aggregator.push(&traffic("t2", "2024-05-01T22:00:00Z", 2, 1.0)));
+    assert!(aggregator.push(&conversion("c1", "2024-05-01T23:00:00Z", 1, 20.0)));
+
+    let updated = aggregator.take_updated();
+    assert_eq!(updated.len(), 2);
+    assert!(aggregator.take_updated().is_empty());
+
+    // 迟到的修正消息
+    assert!(aggregator.push(&conversion("c2", "2024-05-01T23:00:00Z", -1, -20.0)));
+    let updated = aggregator.take_updated();
+    assert_eq!(updated.len(), 1);
+    let (key, metrics) = &updated[0];
+    assert_eq!(
+        key.hour.naive_local(),
+        NaiveDate::from_ymd_opt(2024, 5, 2)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    );
+    assert_eq!(metrics.clicks, 3);
+    assert_eq!(metrics.purchases_7d, 0);
+
+    let rows = vec![
+        json!({"date": "2024-05-01", "campaignId": 1, "clicks": 5}),
+        json!({"date": "2024-05-01", "campaignId": "c1", "clicks": 2}),
+        json!({"date": "2024-05-02", "campaignId": "c1", "adGroupId": "g1", "clicks": 3}),
+    ];
+    let joined = aggregator.join_report_rows(&rows);
+    assert_eq!(joined.len(), 3);
+    assert!(joined[0].stream.is_none());
+    assert_eq!(
+        joined[1].stream.as_ref().unwrap().clicks,
+        joined[1].report.clicks
+    );
+    assert_eq!(
+        joined[2].stream.as_ref().unwrap().clicks,
+        joined[2].report.clicks
+    );
+}
+
+#[test]
+fn aggregate_hourly_across_dst_fall_back() {
+    let mut aggregator = HourlyAggregator::from_timezone("Europe/London").unwrap();
+
+    // 2024-10-27 英国夏令时结束, UTC 00:00 和 01:00 本地都是 01:00
+    assert!(aggregator.push(&traffic("t1", "2024-10-27T00:00:00Z", 3, 1.5)));
+    assert!(aggregator.push(&traffic("t2", "2024-10-27T01:00:00Z", 2, 1.0)));
+
+    let hours: Vec<_> = aggregator.iter().collect();
+    assert_eq!(hours.len(), 2);
+    assert_eq!(hours[0].0.hour.naive_local(), hours[1].0.hour.naive_local());
+    assert_ne!(hours[0].0.hour, hours[1].0.hour);
+    assert_eq!(hours[0].1.clicks, 3);
+    assert_eq!(hours[1].1.clicks, 2);
+
+    let daily = aggregator.daily();
+    assert_eq!(daily.len(), 1);
+    let (key, metrics) = daily.iter().next().unwrap();
+    assert_eq!(key.date, NaiveDate::from_ymd_opt(2024, 10, 27).unwrap());
+    assert_eq!(metrics.clicks, 5);
+}
+
+#[test]
+fn evict_seen_ids_outside_retention() {
+    let mut aggregator = HourlyAggregator::from_timezone("Europe/London")
+        .unwrap()
+        .seen_retention(TimeDelta::days(2));
+
+    assert!(aggregator.push(&traffic("t1", "2024-05-01T00:00:00Z", 1, 1.0)));
+    assert!(aggregator.push(&traffic("t2", "2024-05-02T00:00:00Z", 1, 1.0)));
+    assert_eq!(aggregator.seen_len(), 2);
+
+    // 超出保留期的 id 被清理, 保留期内的仍然去重
+    assert!(aggregator.push(&traffic("t3", "2024-05-03T12:00:00Z", 1, 1.0)));
+    assert_eq!(aggregator.seen_len(), 2);
+    assert!(!aggregator.push(&traffic("t2", "2024-05-02T00:00:00Z", 1, 1.0)));
+}
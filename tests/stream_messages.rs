@@ -0,0 +1,70 @@
+use amazon_ads_api::stream::messages::StreamMessage;
+use amazon_ads_api::stream::subscriptions::StreamDataset;
+
+#[test]
+fn decode_stream_messages() {
+    let traffic = r#"{"idempotency_id":"i-1","dataset_id":"sp-traffic","marketplace_id":"A1F83G8C2ARO7P","currency":"GBP","advertiser_id":"ENTITY1","campaign_id":"c1","ad_group_id":"g1","ad_id":"a1","keyword_id":"k1","keyword_text":"shoes","match_type":"EXACT","placement":"Top of Search on-Amazon","time_window_start":"2024-05-01T10:00:00Z","clicks":3,"impressions":120,"cost":1.52}"#;
+    let conversion = r#"{"idempotency_id":"i-2","dataset_id":"sp-conversion","marketplace_id":"A1F83G8C2ARO7P","currency":"GBP","advertiser_id":"ENTITY1","campaign_id":"c1","time_window_start":"2024-05-01T10:00:00Z","attributed_conversions_7d":2,"attributed_sales_7d":39.98}"#;
+    let unknown = r#"{"dataset_id":"sb-clickstream","foo":1}"#;
+
+    let records = format!("{}\n{}\n{}", traffic, conversion, unknown);
+    let messages = StreamMessage::from_json_records(&records).unwrap();
+    assert_eq!(messages.len(), 3);
+    let StreamMessage::SpTraffic(traffic) = &messages[0] else {
+        panic!("expected sp-traffic");
+    };
+    assert_eq!(traffic.clicks, 3);
+    let StreamMessage::SpConversion(conversion) = &messages[1] else {
+        panic!("expected sp-conversion");
+    };
+    assert_eq!(conversion.attributed_conversions_7d, 2);
+    assert!(matches!(messages[2], StreamMessage::Other { .. }));
+
+    let dataset: StreamDataset = serde_json::from_str(r#""sp-traffic""#).unwrap();
+    assert_eq!(dataset, StreamDataset::SpTraffic);
+    let dataset: StreamDataset = serde_json::from_str(r#""sb-clickstream""#).unwrap();
+    assert_eq!(dataset, StreamDataset::Other("sb-clickstream".to_string()));
+    assert_eq!(StreamDataset::SpConversion.to_string(), "sp-conversion");
+}
+
+#[test]
+fn decode_conversion_and_entity_datasets() {
+    let sb_conversion = r#"{"idempotency_id":"i-3","dataset_id":"sb-conversion","marketplace_id":"A1F83G8C2ARO7P","currency":"GBP","advertiser_id":"ENTITY1","campaign_id":"c2","time_window_start":"2024-05-01T10:00:00Z","attributed_sales_14d":59.97,"attributed_new_to_brand_purchases_14d":1}"#;
+    let sd_conversion = r#"{"idempotency_id":"i-4","dataset_id":"sd-conversion","marketplace_id":"A1F83G8C2ARO7P","currency":"GBP","advertiser_id":"ENTITY1","campaign_id":"c3","target_id":"t3","cost_type":"VCPM","time_window_start":"2024-05-01T10:00:00Z","attributed_conversions_14d":1,"view_attributed_sales_14d":19.99}"#;
+    let sp_ad = r#"{"dataset_id":"sp-ads","advertiser_id":"ENTITY1","marketplace_id":"A1F83G8C2ARO7P","ad_id":"a1","ad_group_id":"g1","campaign_id":"c1","state":"PAUSED"}"#;
+    let sp_target = r#"{"dataset_id":"sp-targets","advertiser_id":"ENTITY1","marketplace_id":"A1F83G8C2ARO7P","target_id":"t1","ad_group_id":"g1","campaign_id":"c1","target_type":"KEYWORD","negative":true}"#;
+    let budget_recommendation = r#"{"dataset_id":"sp-budget-recommendations","advertiser_id":"ENTITY1","marketplace_id":"A1F83G8C2ARO7P","campaign_id":"c1","suggested_budget":25.0,"seven_days_missed_opportunities":{"percent_time_in_budget":62.5}}"#;
+
+    let records = [
+        sb_conversion,
+        sd_conversion,
+        sp_ad,
+        sp_target,
+        budget_recommendation,
+    ]
+    .join("\n");
+    let messages = StreamMessage::from_json_records(&records).unwrap();
+
+    let StreamMessage::SbConversion(message) = &messages[0] else {
+        panic!("expected sb-conversion");
+    };
+    assert_eq!(message.attributed_sales_14d, 59.97);
+    assert_eq!(message.attributed_new_to_brand_purchases_14d, 1);
+    let StreamMessage::SdConversion(message) = &messages[1] else {
+        panic!("expected sd-conversion");
+    };
+    assert_eq!(message.view_attributed_sales_14d, 19.99);
+    assert_eq!(message.target_id.as_deref(), Some("t3"));
+    let StreamMessage::SpAds(message) = &messages[2] else {
+        panic!("expected sp-ads");
+    };
+    assert_eq!(message.state.as_deref(), Some("PAUSED"));
+    let StreamMessage::SpTargets(message) = &messages[3] else {
+        panic!("expected sp-targets");
+    };
+    assert!(message.negative);
+    let StreamMessage::SpBudgetRecommendations(message) = &messages[4] else {
+        panic!("expected sp-budget-recommendations");
+    };
+    assert_eq!(message.suggested_budget, Some(25.0));
+}
@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use amazon_ads_api::stream::subscriptions::ListSubscriptions;
+mod common;
+
+#[tokio::test]
+async fn list_subscriptions_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let req = ListSubscriptions::builder().ads_client(ads_client).build();
+    let res = req.fetch().call().await.unwrap();
+    for subscription in &res.subscriptions {
+        dbg!(&subscription.data_set_id, &subscription.status);
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use amazon_ads_api::ads_v1::sp::ad_groups::{
+    CreateAdGroups, ListAdGroups, ListAdGroupsFilter, UpdateAdGroups,
+};
+use amazon_ads_api::ads_v1::sp::types::ad_groups::{SPAdGroupCreate, SPAdGroupUpdate};
+mod common;
+
+#[tokio::test]
+async fn list_ad_groups_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = ListAdGroupsFilter::builder()
+        .campaign_id_filter(vec!["494576620738611"])
+        .max_results(10)
+        .build();
+    let response = ListAdGroups::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_ad_groups_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SPAdGroupCreate::builder("494576620738611")
+        .name("test-ad-group")
+        .default_bid(0.3)
+        .build();
+    let api = CreateAdGroups::builder()
+        .ad_groups(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn update_ad_groups_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SPAdGroupUpdate::builder("481941107236736")
+        .default_bid(0.25)
+        .build();
+    let api = UpdateAdGroups::builder()
+        .ad_groups(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
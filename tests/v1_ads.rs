@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
-use amazon_ads_api::ads_v1::sp::ads::{CreateAds, DelAds, ListAds, ListAdsFilter};
+use amazon_ads_api::ads_v1::sp::ads::{CreateAds, DelAds, ListAds, ListAdsFilter, UpdateAds};
+use amazon_ads_api::ads_v1::sp::types::ads::SPAdUpdate;
 mod common;
 
 #[tokio::test]
@@ -49,3 +50,18 @@ async fn create_ads_test() {
     let res = api.fetch().await;
     dbg!(&res);
 }
+
+#[tokio::test]
+async fn update_ads_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SPAdUpdate::builder("332526858188251")
+        .state("PAUSED")
+        .build();
+    let api = UpdateAds::builder()
+        .ads(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use amazon_ads_api::ads_v1::sp::campaigns::{
+    CreateCampaigns, ListCampaigns, ListCampaignsFilter, UpdateCampaigns,
+};
+use amazon_ads_api::ads_v1::sp::types::campaigns::{SPCampaignCreate, SPCampaignUpdate};
+use amazon_ads_api::ads_v1::sp::types::enums::{SPBidStrategy, SPPlacement};
+mod common;
+
+#[tokio::test]
+async fn list_campaigns_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = ListCampaignsFilter::builder().max_results(10).build();
+    let response = ListCampaigns::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_campaigns_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SPCampaignCreate::builder("test-manual-campaign")
+        .auto_targeting(false)
+        .daily_budget(10.0)
+        .bid_strategy(SPBidStrategy::SalesDownOnly)
+        .placement_bid_adjustment(SPPlacement::TopOfSearch, 20)
+        .build();
+    let api = CreateCampaigns::builder()
+        .campaigns(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn update_campaigns_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SPCampaignUpdate::builder("494576620738611")
+        .daily_budget(15.0)
+        .state("PAUSED")
+        .build();
+    let api = UpdateCampaigns::builder()
+        .campaigns(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use amazon_ads_api::ads_v1::sp_global::ad_groups::{
+    CreateAdGroups, ListAdGroups, ListAdGroupsFilter,
+};
+use amazon_ads_api::ads_v1::sp_global::types::ad_groups::SPGlobalAdGroupCreate;
+use amazon_ads_api::ads_v1::sp_global::types::enums::SPGlobalMarketplace;
+mod common;
+
+#[tokio::test]
+async fn list_global_ad_groups_test() {
+    let ads_client = common::get_ads_client(Some(&common::account_id()), None);
+    let ads_client = Arc::new(ads_client);
+    let filter = ListAdGroupsFilter::builder().max_results(10).build();
+    let response = ListAdGroups::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_global_ad_groups_test() {
+    let ads_client = common::get_ads_client(Some(&common::account_id()), None);
+    let ads_client = Arc::new(ads_client);
+    let body = SPGlobalAdGroupCreate::builder("4999955411336918637")
+        .name("test-global-ad-group")
+        .marketplaces(vec![SPGlobalMarketplace::GB])
+        .default_bid("GB", 0.3)
+        .build();
+    let api = CreateAdGroups::builder()
+        .ad_groups(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use amazon_ads_api::ads_v1::sp_global::ads::{CreateAds, ListAds, ListAdsFilter};
+use amazon_ads_api::ads_v1::sp_global::ads::{
+    CreateAds, DelAds, ListAds, ListAdsFilter, UpdateAds,
+};
+use amazon_ads_api::ads_v1::sp_global::types::ads::SPGlobalAdUpdate;
 mod common;
 
 #[tokio::test]
@@ -43,3 +46,31 @@ async fn create_ads_test() {
     let res = api.fetch().await;
     dbg!(&res);
 }
+
+#[tokio::test]
+async fn update_ads_test() {
+    let ads_client = common::get_ads_client(Some(&common::account_id()), None);
+    let ads_client = Arc::new(ads_client);
+    let body = SPGlobalAdUpdate::builder("4999899225094945252")
+        .state("PAUSED")
+        .build();
+    let api = UpdateAds::builder()
+        .ads(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn del_ads_test() {
+    let ads_client = common::get_ads_client(Some(&common::account_id()), None);
+    let ads_client = Arc::new(ads_client);
+    let response = DelAds::builder()
+        .ads_client(ads_client)
+        .ad_ids(vec!["4999899225094945252"])
+        .build()
+        .fetch()
+        .await;
+    dbg!(&response);
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use amazon_ads_api::ads_v1::sp_global::campaigns::{
+    CreateCampaigns, ListCampaigns, ListCampaignsFilter,
+};
+use amazon_ads_api::ads_v1::sp_global::types::campaigns::SPGlobalCampaignCreate;
+use amazon_ads_api::ads_v1::sp_global::types::enums::{SPGlobalMarketplace, SPGlobalPlacement};
+mod common;
+
+#[tokio::test]
+async fn list_global_campaigns_test() {
+    let ads_client = common::get_ads_client(Some(&common::account_id()), None);
+    let ads_client = Arc::new(ads_client);
+    let filter = ListCampaignsFilter::builder().max_results(10).build();
+    let response = ListCampaigns::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_global_campaigns_test() {
+    let ads_client = common::get_ads_client(Some(&common::account_id()), None);
+    let ads_client = Arc::new(ads_client);
+    let body = SPGlobalCampaignCreate::builder("test-global-campaign")
+        .auto_targeting(true)
+        .marketplaces(vec![SPGlobalMarketplace::GB, SPGlobalMarketplace::DE])
+        .daily_budget("GB", 10.0)
+        .daily_budget("DE", 12.0)
+        .marketplace_placement_bid_adjustment("DE", SPGlobalPlacement::TopOfSearch, 30)
+        .build();
+    let api = CreateCampaigns::builder()
+        .campaigns(vec![body])
+        .ads_client(ads_client)
+        .build();
+    let res = api.fetch().await;
+    dbg!(&res);
+}
+
+#[test]
+fn create_global_campaign_without_budget_omits_budgets() {
+    let body = SPGlobalCampaignCreate::builder("test-global-campaign")
+        .marketplaces(vec![SPGlobalMarketplace::GB])
+        .build();
+    let value = serde_json::to_value(&body).unwrap();
+    assert!(value.get("budgets").is_none());
+
+    let body = SPGlobalCampaignCreate::builder("test-global-campaign")
+        .marketplaces(vec![SPGlobalMarketplace::GB])
+        .daily_budget("GB", 10.0)
+        .build();
+    let value = serde_json::to_value(&body).unwrap();
+    assert_eq!(value["budgets"].as_array().unwrap().len(), 1);
+}
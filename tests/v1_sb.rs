@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use amazon_ads_api::ads_v1::sb::campaigns::{ListCampaigns, ListCampaignsFilter, UpdateCampaigns};
+use amazon_ads_api::ads_v1::sb::targets::{ListTargets, ListTargetsFilter};
+use amazon_ads_api::ads_v1::sb::types::campaigns::SBCampaignUpdate;
+mod common;
+
+#[tokio::test]
+async fn list_v1_sb_campaigns_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = ListCampaignsFilter::builder().max_results(10).build();
+    let response = ListCampaigns::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn update_v1_sb_campaigns_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SBCampaignUpdate::builder("494576620738611")
+        .daily_budget(30.0)
+        .state("ENABLED")
+        .build();
+    let res = UpdateCampaigns::builder()
+        .campaigns(vec![body])
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn list_v1_sb_targets_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = ListTargetsFilter::builder()
+        .campaign_id_filter(vec!["494576620738611"])
+        .build();
+    let response = ListTargets::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use amazon_ads_api::v3::bid_recommendations::{
+    BidRecommendations, BidRecommendationsFilterForASINS, BidRecommendationsFilterForAdGroup,
+    BidRecommendationsResponse, BiddingStrategy, PlacementPredicate, TargetingExpression,
+    TargetingExpressionType,
+};
+use amazon_ads_api::v3::types::enums::MatchType;
+mod common;
+
+#[tokio::test]
+async fn bid_recommendations_for_ad_group() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = BidRecommendationsFilterForAdGroup::builder()
+        .campaign_id("494576620738611")
+        .ad_group_id("494576620738612")
+        .expression(TargetingExpression::keyword("phone case", MatchType::Exact))
+        .expression(TargetingExpression::asin("B0FMS7N9W5"))
+        .build();
+    let response = BidRecommendations::builder()
+        .ads_client(ads_client)
+        .filter(filter.into())
+        .build()
+        .fetch()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn bid_recommendations_for_asins() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = BidRecommendationsFilterForASINS::builder()
+        .asins(vec!["B0FMS7N9W5"])
+        .expressions(TargetingExpression::auto_targets())
+        .strategy(BiddingStrategy::AutoForSales)
+        .placement_adjustment(PlacementPredicate::PlacementTop, 50)
+        .build();
+    let response = BidRecommendations::builder()
+        .ads_client(ads_client)
+        .filter(filter.into())
+        .build()
+        .fetch()
+        .await
+        .unwrap();
+    for theme in &response.bid_recommendations {
+        for target in &theme.bid_recommendations_for_targeting_expressions {
+            dbg!(&theme.theme, target.suggested_bid());
+        }
+    }
+}
+
+#[test]
+fn parse_unknown_targeting_expression_type() {
+    let data = r#"{"bidRecommendations":[{"theme":"CONVERSION_OPPORTUNITIES","bidRecommendationsForTargetingExpressions":[
+        {"targetingExpression":{"type":"KEYWORD_BROAD_MATCH","value":"shoes"},"bidValues":[{"suggestedBid":0.5}]},
+        {"targetingExpression":{"type":"NEW_EXPRESSION"},"bidValues":[{"suggestedBid":0.8}]}
+    ]}]}"#;
+    let res: BidRecommendationsResponse = serde_json::from_str(data).unwrap();
+    let targets = &res.bid_recommendations[0].bid_recommendations_for_targeting_expressions;
+    assert_eq!(
+        targets[0].targeting_expression.expression_type,
+        TargetingExpressionType::KeywordBroadMatch
+    );
+    assert_eq!(
+        targets[1].targeting_expression.expression_type,
+        TargetingExpressionType::Other
+    );
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use amazon_ads_api::v3::budget_rules::{
+    AssociateBudgetRules, BudgetIncreaseBy, BudgetRuleDetails, BudgetRuleEventRecommendation,
+    BudgetRuleHistory, BudgetRuleRecommendations, BudgetRuleRecurrence, ComparisonOperator,
+    CreateBudgetRules, DayOfWeek, DisassociateBudgetRule, ListBudgetRules, ListCampaignBudgetRules,
+    PerformanceMetric,
+};
+mod common;
+
+#[tokio::test]
+async fn create_budget_rules_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let weekend = BudgetRuleDetails::builder("weekend boost")
+        .schedule_date_range("20261101", None)
+        .recurrence(BudgetRuleRecurrence::weekly(vec![
+            DayOfWeek::Saturday,
+            DayOfWeek::Sunday,
+        ]))
+        .budget_increase_by(BudgetIncreaseBy::percent(30.0))
+        .build();
+    let low_acos = BudgetRuleDetails::builder("low acos boost")
+        .schedule_date_range("20261101", Some("20261231"))
+        .recurrence(BudgetRuleRecurrence::daily())
+        .performance(PerformanceMetric::Acos, ComparisonOperator::LessThan, 25.0)
+        .budget_increase_by(BudgetIncreaseBy::percent(20.0))
+        .build();
+    let res = CreateBudgetRules::builder()
+        .ads_client(ads_client)
+        .rules(vec![weekend, low_acos])
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn list_budget_rules_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let response = ListBudgetRules::builder()
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn campaign_budget_rules_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let campaign_id = "494576620738611";
+
+    let recommendations = BudgetRuleRecommendations::builder()
+        .ads_client(ads_client.clone())
+        .campaign_id(campaign_id)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&recommendations);
+
+    let res = AssociateBudgetRules::builder()
+        .ads_client(ads_client.clone())
+        .campaign_id(campaign_id)
+        .rule_ids(vec!["rule-id-1"])
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+
+    let rules = ListCampaignBudgetRules::builder()
+        .ads_client(ads_client.clone())
+        .campaign_id(campaign_id)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&rules);
+
+    let history = BudgetRuleHistory::builder()
+        .ads_client(ads_client.clone())
+        .campaign_id(campaign_id)
+        .start_date("20261001")
+        .end_date("20261019")
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&history);
+
+    let res = DisassociateBudgetRule::builder()
+        .ads_client(ads_client)
+        .campaign_id(campaign_id)
+        .rule_id("rule-id-1")
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[test]
+fn budget_rule_recommendation_without_percent() {
+    let recommendation: BudgetRuleEventRecommendation = serde_json::from_value(serde_json::json!({
+        "eventId": "event-1",
+        "eventName": "Prime Day",
+    }))
+    .unwrap();
+    assert!(recommendation.to_rule_details("prime day").is_none());
+
+    let details = recommendation.to_rule_details_with_percent("prime day", 20.0);
+    assert_eq!(details.name, "prime day");
+}
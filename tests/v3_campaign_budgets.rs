@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use amazon_ads_api::v3::campaign_budgets::{CampaignBudgetRecommendations, CampaignBudgetUsage};
+mod common;
+
+#[tokio::test]
+async fn campaign_budget_usage_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let response = CampaignBudgetUsage::builder()
+        .ads_client(ads_client)
+        .campaign_ids(vec!["494576620738611"])
+        .build()
+        .fetch()
+        .await
+        .unwrap();
+    dbg!(&response);
+    dbg!(response.running_out(80.0));
+}
+
+#[tokio::test]
+async fn campaign_budget_recommendations_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let response = CampaignBudgetRecommendations::builder()
+        .ads_client(ads_client)
+        .campaign_ids(vec!["494576620738611"])
+        .build()
+        .fetch()
+        .await;
+    dbg!(&response);
+}
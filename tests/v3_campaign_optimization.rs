@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use amazon_ads_api::v3::campaign_optimization::{
+    CampaignOptimizationComparisonOperator, CampaignOptimizationEligibility,
+    CampaignOptimizationMetric, CampaignOptimizationRule, CampaignOptimizationRuleAction,
+    CampaignOptimizationRuleCreate, CampaignOptimizationRuleState, CreateCampaignOptimizationRule,
+    DelCampaignOptimizationRule, GetCampaignOptimizationRule,
+};
+mod common;
+
+#[tokio::test]
+async fn campaign_optimization_rule_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let campaign_id = "494576620738611";
+
+    let eligibility = CampaignOptimizationEligibility::builder()
+        .ads_client(ads_client.clone())
+        .campaign_ids(vec![campaign_id])
+        .build()
+        .fetch()
+        .await;
+    dbg!(&eligibility);
+
+    let rule = CampaignOptimizationRuleCreate::builder("roas 3.5")
+        .campaign_ids(vec![campaign_id])
+        .roas_target(3.5)
+        .build();
+    let created = CreateCampaignOptimizationRule::builder()
+        .ads_client(ads_client.clone())
+        .rule(rule)
+        .build()
+        .fetch()
+        .await
+        .unwrap();
+    dbg!(&created);
+
+    let state = CampaignOptimizationRuleState::builder()
+        .ads_client(ads_client.clone())
+        .campaign_ids(vec![campaign_id])
+        .build()
+        .fetch()
+        .await;
+    dbg!(&state);
+
+    let Some(id) = created.campaign_optimization_id else {
+        return;
+    };
+    let rule = GetCampaignOptimizationRule::builder()
+        .ads_client(ads_client.clone())
+        .campaign_optimization_id(id.clone())
+        .build()
+        .fetch()
+        .await;
+    dbg!(&rule);
+    let res = DelCampaignOptimizationRule::builder()
+        .ads_client(ads_client)
+        .campaign_optimization_id(id)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[test]
+fn parse_rule_with_unknown_enums() {
+    let data = r#"{"campaignOptimizationId":"r1","campaignIds":["c1"],"ruleAction":"PAUSE",
+        "ruleType":"BUDGET","ruleCondition":[{"metricName":"ACOS","comparisonOperator":"LESS_THAN","value":0.3}]}"#;
+    let rule: CampaignOptimizationRule = serde_json::from_str(data).unwrap();
+    assert_eq!(
+        rule.rule_action,
+        Some(CampaignOptimizationRuleAction::Other)
+    );
+    let condition = &rule.rule_condition[0];
+    assert_eq!(condition.metric_name, CampaignOptimizationMetric::Other);
+    assert_eq!(
+        condition.comparison_operator,
+        CampaignOptimizationComparisonOperator::Other
+    );
+}
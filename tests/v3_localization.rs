@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use amazon_ads_api::v3::localization::{LocalizeKeywords, LocalizeProducts};
+use amazon_ads_api::v3::types::enums::Locale;
+mod common;
+
+#[tokio::test]
+async fn localize_keywords_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+
+    let res = LocalizeKeywords::builder()
+        .ads_client(ads_client)
+        .source_country_code("US")
+        .source_locale(Locale::EnUs)
+        .target_country_codes(vec!["DE"])
+        .target_locales(vec![Locale::DeDe])
+        .keywords(vec!["running shoes"])
+        .build()
+        .fetch()
+        .await
+        .unwrap();
+    for item in &res {
+        dbg!(item.localized(&Locale::DeDe));
+    }
+}
+
+#[tokio::test]
+async fn localize_products_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+
+    let res = LocalizeProducts::builder()
+        .ads_client(ads_client)
+        .source_country_code("US")
+        .target_country_codes(vec!["DE", "FR"])
+        .asin("B0CHX3QBCH")
+        .build()
+        .fetch()
+        .await
+        .unwrap();
+    for item in &res {
+        dbg!(item.localized("DE"));
+    }
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use amazon_ads_api::ads_v1::sp::campaigns::CreateCampaigns;
+use amazon_ads_api::ads_v1::sp::types::campaigns::SPCampaignCreate;
+use amazon_ads_api::v3::portfolios::{
+    CreatePortfolios, ListPortfolios, ListPortfoliosFilter, PortfolioCreate, PortfolioState,
+    PortfolioUpdate, UpdatePortfolios,
+};
+use chrono::NaiveDate;
+mod common;
+
+#[tokio::test]
+async fn list_portfolios_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let filter = ListPortfoliosFilter::builder()
+        .include_extended_data_fields(true)
+        .state_filter(vec![PortfolioState::Enabled])
+        .build();
+    let response = ListPortfolios::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .call()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn create_portfolios_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let date_range = PortfolioCreate::builder("test-portfolio-date-range")
+        .date_range_budget(
+            500.0,
+            "USD",
+            NaiveDate::from_ymd_opt(2026, 11, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 11, 30).unwrap(),
+        )
+        .build();
+    let monthly = PortfolioCreate::builder("test-portfolio-monthly")
+        .monthly_recurring_budget(1000.0, "USD", None)
+        .build();
+    let res = CreatePortfolios::builder()
+        .portfolios(vec![date_range, monthly])
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn update_portfolios_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = PortfolioUpdate::builder("123456789").no_cap().build();
+    let res = UpdatePortfolios::builder()
+        .portfolios(vec![body])
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn create_campaign_in_portfolio_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let body = SPCampaignCreate::builder("test-portfolio-campaign")
+        .daily_budget(10.0)
+        .portfolio_id("123456789")
+        .build();
+    let res = CreateCampaigns::builder()
+        .campaigns(vec![body])
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
@@ -1,6 +1,13 @@
 use std::sync::Arc;
 
-use amazon_ads_api::v3::product_targeting::ListNegativeTargetsBrandsSearch;
+use amazon_ads_api::pagination::{collect_all, paginate};
+use amazon_ads_api::v3::product_targeting::{
+    CampaignNegativeTargetCreate, CategoryRecommendationsForASINs, CategoryRefinements,
+    CategoryTree, CreateCampaignNegativeTargets, ListNegativeTargetsBrandsRecommendations,
+    ListNegativeTargetsBrandsSearch, ListTargetableCategories, ProductRecommendations,
+    ProductRecommendationsByTheme, ProductRecommendationsByThemeResponse,
+    ProductRecommendationsResponse, TargetableProductsCount, TargetableProductsCountFilter,
+};
 mod common;
 
 #[tokio::test]
@@ -15,3 +22,180 @@ async fn list_brand_search() {
     let response = api.fetch().await.unwrap();
     dbg!(&response);
 }
+
+#[tokio::test]
+async fn negative_brand_recommendations() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let brands = ListNegativeTargetsBrandsRecommendations::builder()
+        .ads_client(ads_client.clone())
+        .build()
+        .fetch()
+        .await
+        .unwrap();
+    dbg!(&brands);
+
+    let negative_targets: Vec<CampaignNegativeTargetCreate> = brands
+        .iter()
+        .take(2)
+        .map(|brand| brand.to_campaign_negative_target("494576620738611"))
+        .collect();
+    if negative_targets.is_empty() {
+        return;
+    }
+    let res = CreateCampaignNegativeTargets::builder()
+        .ads_client(ads_client)
+        .negative_targets(negative_targets)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&res);
+}
+
+#[tokio::test]
+async fn list_targetable_categories() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let tree = ListTargetableCategories::builder()
+        .ads_client(ads_client)
+        .build()
+        .fetch()
+        .await
+        .unwrap();
+    for node in tree.iter().filter(|node| node.targetable).take(10) {
+        dbg!(&node.id, tree.path(&node.id));
+    }
+}
+
+#[tokio::test]
+async fn category_recommendations_for_asins() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let response = CategoryRecommendationsForASINs::builder()
+        .ads_client(ads_client)
+        .asins(vec!["B0FMS7N9W5"])
+        .include_ancestor(true)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&response);
+}
+
+#[tokio::test]
+async fn category_refinements_and_count() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let refinements = CategoryRefinements::builder()
+        .ads_client(ads_client.clone())
+        .category_id("2335752011")
+        .build()
+        .fetch()
+        .await;
+    dbg!(&refinements);
+    let filter = TargetableProductsCountFilter::builder()
+        .category("2335752011")
+        .rating_range_lower_bound(4.0)
+        .build();
+    let count = TargetableProductsCount::builder()
+        .ads_client(ads_client)
+        .filter(filter)
+        .build()
+        .fetch()
+        .await;
+    dbg!(&count);
+}
+
+#[tokio::test]
+async fn product_recommendations() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+    let api = ProductRecommendations::builder()
+        .ads_client(ads_client.clone())
+        .ad_asins(vec!["B0FMS7N9W5"])
+        .count(50)
+        .build();
+    let first = api.fetch().call().await.unwrap();
+    dbg!(&first.recommendations.len());
+    if let Some(cursor) = first.next_cursor.as_deref() {
+        let second = api.fetch().cursor(cursor).call().await.unwrap();
+        dbg!(&second.recommendations.len());
+    }
+
+    let themes = ProductRecommendationsByTheme::builder()
+        .ads_client(ads_client.clone())
+        .ad_asins(vec!["B0FMS7N9W5"])
+        .build();
+    let first = themes.fetch().call().await.unwrap();
+    if let Some(cursor) = first.next_cursor.as_deref() {
+        let second = themes.fetch().cursor(cursor).call().await.unwrap();
+        dbg!(&second.themes.len());
+    }
+
+    let all = ProductRecommendations::builder()
+        .ads_client(ads_client)
+        .ad_asins(vec!["B0FMS7N9W5"])
+        .count(50)
+        .limit(200)
+        .build()
+        .collect_all()
+        .await;
+    dbg!(all.items.len(), all.error);
+}
+
+#[tokio::test]
+async fn product_recommendations_follow_cursor() {
+    let mut cursors = vec![];
+    let stream = paginate(None, |cursor: Option<String>| {
+        cursors.push(cursor.clone());
+        let body = match cursor.as_deref() {
+            None => {
+                r#"{"nextCursor":"c2","recommendations":[{"recommendedAsin":"A1"},{"recommendedAsin":"A2"}]}"#
+            }
+            Some("c2") => r#"{"previousCursor":"c1","recommendations":[{"recommendedAsin":"A3"}]}"#,
+            Some(cursor) => panic!("unexpected cursor {}", cursor),
+        };
+        async move {
+            let res: ProductRecommendationsResponse = serde_json::from_str(body)?;
+            Ok(res.into_page())
+        }
+    });
+    let asins: Vec<String> = collect_all(stream)
+        .await
+        .into_result()
+        .unwrap()
+        .into_iter()
+        .map(|item| item.recommended_asin)
+        .collect();
+    assert_eq!(asins, vec!["A1", "A2", "A3"]);
+    assert_eq!(cursors, vec![None, Some("c2".to_string())]);
+
+    let stream = paginate(None, |cursor: Option<String>| async move {
+        let body = match cursor.as_deref() {
+            None => {
+                r#"{"nextCursor":"c2","themes":[{"theme":"Top converting targets","recommendations":[{"recommendedAsin":"A1"}]}]}"#
+            }
+            _ => r#"{"themes":[{"theme":"Top clicked targets"}]}"#,
+        };
+        let res: ProductRecommendationsByThemeResponse = serde_json::from_str(body)?;
+        Ok(res.into_page())
+    });
+    let themes = collect_all(stream).await.into_result().unwrap();
+    assert_eq!(themes.len(), 2);
+    assert_eq!(themes[1].theme, "Top clicked targets");
+}
+
+#[test]
+fn category_tree_path() {
+    let json = r#"[{"id":"1","na":"Electronics","ta":true,"ch":[
+        {"id":"2","na":"Accessories","ta":true,"ch":[{"id":"3","na":"Cases","ta":true}]}
+    ]},{"id":"4","na":"Toys","ta":false}]"#;
+    let tree = CategoryTree::from_json(json).unwrap();
+    assert_eq!(
+        tree.path("3").as_deref(),
+        Some("Electronics/Accessories/Cases")
+    );
+    assert_eq!(tree.parent("3").map(|node| node.id.as_str()), Some("2"));
+    assert_eq!(tree.children("1").map(|nodes| nodes.len()), Some(1));
+    assert_eq!(tree.iter().count(), 4);
+    assert!(tree.get("5").is_none());
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use amazon_ads_api::v3::recommendations::{
+    ListRecommendations, RecommendationStatus, RecommendationType,
+};
+mod common;
+
+#[tokio::test]
+async fn list_recommendations_test() {
+    let ads_client = common::get_ads_client(None, Some(&common::profile_id()));
+    let ads_client = Arc::new(ads_client);
+
+    let req = ListRecommendations::builder()
+        .ads_client(ads_client)
+        .recommendation_types(vec![
+            RecommendationType::CampaignBudget,
+            RecommendationType::NewKeyword,
+        ])
+        .status(RecommendationStatus::Published)
+        .build();
+    let res = req.fetch().call().await.unwrap();
+    for recommendation in &res.recommendations {
+        dbg!(recommendation.payload().unwrap());
+    }
+}